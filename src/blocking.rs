@@ -0,0 +1,379 @@
+use embedded_hal::i2c::{I2c, Operation};
+
+use crate::commands::CommandCode;
+use crate::error::PmbusError;
+use crate::formats::{Linear11, ULinear16};
+use crate::pec::{self, Pec, PecMode};
+use crate::status::{
+    StatusByte, StatusCml, StatusInput, StatusIout, StatusOther, StatusTemperature, StatusVout,
+    StatusWord,
+};
+use crate::vout_mode::VoutMode;
+
+/// Generate a blocking read-byte/write-byte pair.
+macro_rules! blocking_byte_rw {
+    ($set:ident, $get:ident, $cmd:ident) => {
+        pub fn $set(&mut self, addr: u8, data: u8) -> Result<(), PmbusError<BUS::Error>> {
+            self.write_cmd_byte(addr, CommandCode::$cmd, data)
+        }
+        pub fn $get(&mut self, addr: u8) -> Result<u8, PmbusError<BUS::Error>> {
+            self.read_cmd_byte(addr, CommandCode::$cmd)
+        }
+    };
+}
+
+/// Generate a blocking read-word/write-word pair.
+macro_rules! blocking_word_rw {
+    ($set:ident, $get:ident, $cmd:ident) => {
+        pub fn $set(&mut self, addr: u8, data: u16) -> Result<(), PmbusError<BUS::Error>> {
+            self.write_cmd_word(addr, CommandCode::$cmd, data)
+        }
+        pub fn $get(&mut self, addr: u8) -> Result<u16, PmbusError<BUS::Error>> {
+            self.read_cmd_word(addr, CommandCode::$cmd)
+        }
+    };
+}
+
+/// Generate a blocking read-word.
+macro_rules! blocking_read_word {
+    ($name:ident, $cmd:ident) => {
+        pub fn $name(&mut self, addr: u8) -> Result<u16, PmbusError<BUS::Error>> {
+            self.read_cmd_word(addr, CommandCode::$cmd)
+        }
+    };
+}
+
+/// Generate a blocking read-word decoded from LINEAR11 to `f32`.
+macro_rules! blocking_read_linear11 {
+    ($name:ident, $cmd:ident) => {
+        pub fn $name(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+            let raw = self.read_cmd_word(addr, CommandCode::$cmd)?;
+            Ok(Linear11::from_raw(raw).to_f32())
+        }
+    };
+}
+
+/// Generate a blocking typed read of a one-byte status register.
+macro_rules! blocking_status_byte {
+    ($name:ident, $ty:ident, $cmd:ident) => {
+        pub fn $name(&mut self, addr: u8) -> Result<$ty, PmbusError<BUS::Error>> {
+            Ok($ty::from_raw(self.read_cmd_byte(addr, CommandCode::$cmd)?))
+        }
+    };
+}
+
+/// Blocking counterpart of [`PmbusAdaptor`](crate::PmbusAdaptor), for
+/// targets without an async executor.
+///
+/// `smbus-adapter` is async-only, so this drives an `embedded-hal` I2C bus
+/// directly. Method names match the async adapter, so switching is a type
+/// alias and dropping `.await`. Only a bring-up subset is provided: PAGE,
+/// OPERATION, CLEAR_FAULTS, VOUT_MODE and VOUT_COMMAND, the READ_*
+/// telemetry, and the status registers. Anything else is reachable through
+/// the `raw_*` methods.
+///
+/// PEC follows [`PecMode`]; a bad PEC byte always fails the read with
+/// [`PmbusError::PecMismatch`]. There is no page cache, retry policy or
+/// dry-run recording.
+pub struct PmbusAdaptorBlocking<BUS: I2c> {
+    bus: BUS,
+    pec_mode: PecMode,
+}
+
+impl<BUS: I2c> PmbusAdaptorBlocking<BUS> {
+    /// Create a new blocking PMBus adapter on the given I2C bus.
+    pub fn new(bus: BUS) -> Self {
+        Self {
+            bus,
+            pec_mode: PecMode::Off,
+        }
+    }
+
+    /// Consume self and return the inner bus.
+    pub fn release(self) -> BUS {
+        self.bus
+    }
+
+    /// Borrow the inner bus mutably.
+    pub fn inner(&mut self) -> &mut BUS {
+        &mut self.bus
+    }
+
+    /// Select how PEC is applied to transactions. [`PecMode::Auto`] uses
+    /// [`pec::default_pec_required`].
+    pub fn set_pec_mode(&mut self, mode: PecMode) {
+        self.pec_mode = mode;
+    }
+
+    /// Return the current PEC mode.
+    pub fn pec_mode(&self) -> PecMode {
+        self.pec_mode
+    }
+
+    /// Turn PEC on for every transaction, or off entirely.
+    pub fn enable_pec(&mut self, on: bool) {
+        self.pec_mode = if on { PecMode::On } else { PecMode::Off };
+    }
+
+    // -----------------------------------------------------------------------
+    // Private helpers
+    // -----------------------------------------------------------------------
+
+    fn pec_for(&self, code: u8) -> bool {
+        match self.pec_mode {
+            PecMode::Off => false,
+            PecMode::On => true,
+            PecMode::Auto => CommandCode::from_u8(code).is_none_or(pec::default_pec_required),
+        }
+    }
+
+    /// Write `bytes` (code first), plus a PEC byte if enabled.
+    fn write_frame(&mut self, addr: u8, bytes: &[u8]) -> Result<(), PmbusError<BUS::Error>> {
+        if self.pec_for(bytes[0]) {
+            let crc = [Pec::new().addr_write(addr).bytes(bytes).finish()];
+            let mut ops = [Operation::Write(bytes), Operation::Write(&crc)];
+            self.bus.transaction(addr, &mut ops)?;
+        } else {
+            self.bus.write(addr, bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Write `cmd`, repeated start, then fill `buf` and check PEC if enabled.
+    fn read_frame(
+        &mut self,
+        addr: u8,
+        cmd: &[u8],
+        buf: &mut [u8],
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        if !self.pec_for(cmd[0]) {
+            self.bus.write_read(addr, cmd, buf)?;
+            return Ok(());
+        }
+        let mut received = [0u8];
+        let mut ops = [
+            Operation::Write(cmd),
+            Operation::Read(buf),
+            Operation::Read(&mut received),
+        ];
+        self.bus.transaction(addr, &mut ops)?;
+        let expected = Pec::new()
+            .addr_write(addr)
+            .bytes(cmd)
+            .addr_read(addr)
+            .bytes(buf)
+            .finish();
+        if expected != received[0] {
+            return Err(PmbusError::PecMismatch {
+                expected,
+                received: received[0],
+            });
+        }
+        Ok(())
+    }
+
+    fn read_cmd_byte(&mut self, addr: u8, cmd: CommandCode) -> Result<u8, PmbusError<BUS::Error>> {
+        self.raw_read_byte(addr, cmd.code())
+    }
+
+    fn write_cmd_byte(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+        data: u8,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.raw_write_byte(addr, cmd.code(), data)
+    }
+
+    fn read_cmd_word(&mut self, addr: u8, cmd: CommandCode) -> Result<u16, PmbusError<BUS::Error>> {
+        self.raw_read_word(addr, cmd.code())
+    }
+
+    fn write_cmd_word(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+        data: u16,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.raw_write_word(addr, cmd.code(), data)
+    }
+
+    fn vout_exponent(&mut self, addr: u8) -> Result<i8, PmbusError<BUS::Error>> {
+        crate::exponent_of(self.get_vout_mode(addr)?)
+    }
+
+    // =======================================================================
+    // Control
+    // =======================================================================
+
+    blocking_byte_rw!(set_page, get_page, Page);
+    blocking_byte_rw!(set_operation, get_operation, Operation);
+
+    /// Send CLEAR_FAULTS (0x03).
+    pub fn clear_faults(&mut self, addr: u8) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_frame(addr, &[CommandCode::ClearFaults.code()])
+    }
+
+    // =======================================================================
+    // Output voltage
+    // =======================================================================
+
+    /// Read VOUT_MODE (0x20) and parse into `VoutMode`.
+    pub fn get_vout_mode(&mut self, addr: u8) -> Result<VoutMode, PmbusError<BUS::Error>> {
+        Ok(VoutMode::from_raw(
+            self.read_cmd_byte(addr, CommandCode::VoutMode)?,
+        ))
+    }
+
+    blocking_word_rw!(set_vout_command, get_vout_command, VoutCommand);
+
+    /// Write VOUT_COMMAND (0x21) in volts, using the VOUT_MODE exponent.
+    ///
+    /// Returns [`PmbusError::RelativeModeUnsupported`] if VOUT_MODE has the
+    /// relative bit set.
+    pub fn set_vout_command_volts(
+        &mut self,
+        addr: u8,
+        volts: f32,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let mode = self.get_vout_mode(addr)?;
+        if mode.relative {
+            return Err(PmbusError::RelativeModeUnsupported);
+        }
+        let exponent = crate::exponent_of(mode)?;
+        let raw = ULinear16::from_f32(volts, exponent).ok_or(PmbusError::EncodingError)?;
+        self.write_cmd_word(addr, CommandCode::VoutCommand, raw.raw())
+    }
+
+    // =======================================================================
+    // Telemetry
+    // =======================================================================
+
+    blocking_read_word!(read_vin, ReadVin);
+    blocking_read_word!(read_iin, ReadIin);
+    blocking_read_word!(read_vout, ReadVout);
+    blocking_read_word!(read_iout, ReadIout);
+    blocking_read_word!(read_temperature_1, ReadTemperature1);
+    blocking_read_word!(read_temperature_2, ReadTemperature2);
+    blocking_read_word!(read_temperature_3, ReadTemperature3);
+    blocking_read_word!(read_pout, ReadPout);
+    blocking_read_word!(read_pin, ReadPin);
+
+    blocking_read_linear11!(read_vin_volts, ReadVin);
+    blocking_read_linear11!(read_iin_amps, ReadIin);
+    blocking_read_linear11!(read_iout_amps, ReadIout);
+    blocking_read_linear11!(read_temperature_1_celsius, ReadTemperature1);
+    blocking_read_linear11!(read_temperature_2_celsius, ReadTemperature2);
+    blocking_read_linear11!(read_temperature_3_celsius, ReadTemperature3);
+    blocking_read_linear11!(read_pout_watts, ReadPout);
+    blocking_read_linear11!(read_pin_watts, ReadPin);
+
+    /// Read READ_VOUT (0x8B) in volts, decoded with the VOUT_MODE exponent.
+    ///
+    /// Returns [`PmbusError::UnsupportedVoutMode`] if VOUT_MODE selects VID,
+    /// DIRECT or IEEE half.
+    pub fn read_vout_f32(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+        let exponent = self.vout_exponent(addr)?;
+        let raw = self.read_cmd_word(addr, CommandCode::ReadVout)?;
+        crate::decode_vout(raw, exponent)
+    }
+
+    // =======================================================================
+    // Status
+    // =======================================================================
+
+    blocking_status_byte!(get_status_byte, StatusByte, StatusByte);
+    blocking_status_byte!(get_status_vout, StatusVout, StatusVout);
+    blocking_status_byte!(get_status_iout, StatusIout, StatusIout);
+    blocking_status_byte!(get_status_input, StatusInput, StatusInput);
+    blocking_status_byte!(get_status_temperature, StatusTemperature, StatusTemperature);
+    blocking_status_byte!(get_status_cml, StatusCml, StatusCml);
+    blocking_status_byte!(get_status_other, StatusOther, StatusOther);
+
+    /// Read STATUS_WORD (0x79).
+    pub fn get_status_word(&mut self, addr: u8) -> Result<StatusWord, PmbusError<BUS::Error>> {
+        Ok(StatusWord::from_raw(
+            self.read_cmd_word(addr, CommandCode::StatusWord)?,
+        ))
+    }
+
+    // =======================================================================
+    // Raw methods for manufacturer-specific codes
+    // =======================================================================
+
+    /// Read a byte from any command code.
+    pub fn raw_read_byte(&mut self, addr: u8, code: u8) -> Result<u8, PmbusError<BUS::Error>> {
+        let mut buf = [0u8];
+        self.read_frame(addr, &[code], &mut buf)?;
+        Ok(buf[0])
+    }
+
+    /// Write a byte to any command code.
+    pub fn raw_write_byte(
+        &mut self,
+        addr: u8,
+        code: u8,
+        data: u8,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_frame(addr, &[code, data])
+    }
+
+    /// Read a word from any command code.
+    pub fn raw_read_word(&mut self, addr: u8, code: u8) -> Result<u16, PmbusError<BUS::Error>> {
+        let mut buf = [0u8; 2];
+        self.read_frame(addr, &[code], &mut buf)?;
+        Ok(u16::from_le_bytes(buf))
+    }
+
+    /// Write a word to any command code.
+    pub fn raw_write_word(
+        &mut self,
+        addr: u8,
+        code: u8,
+        data: u16,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let [lo, hi] = data.to_le_bytes();
+        self.write_frame(addr, &[code, lo, hi])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockBus;
+
+    #[test]
+    fn telemetry_and_vout_command() {
+        // VOUT_MODE exponent -9, READ_VOUT 3.0 V, READ_IOUT 12.5 A, VOUT_MODE.
+        let bus = MockBus::with_reads(&[0x17, 0x00, 0x06, 0x19, 0xF8, 0x17]);
+        let mut pmbus = PmbusAdaptorBlocking::new(bus.clone());
+        assert_eq!(pmbus.read_vout_f32(0x40).unwrap(), 3.0);
+        assert_eq!(pmbus.read_iout_amps(0x40).unwrap(), 12.5);
+        pmbus.set_vout_command_volts(0x40, 1.2).unwrap();
+        let writes = bus.writes();
+        assert_eq!(writes.last().unwrap().1, [0x21, 0x66, 0x02]);
+    }
+
+    #[test]
+    fn pec_mismatch_fails_read() {
+        let good = Pec::new()
+            .addr_write(0x40)
+            .update(0x78)
+            .addr_read(0x40)
+            .update(0x42)
+            .finish();
+        let bus = MockBus::with_reads(&[0x42, good, 0x42, good ^ 1]);
+        let mut pmbus = PmbusAdaptorBlocking::new(bus.clone());
+        pmbus.enable_pec(true);
+        assert_eq!(
+            pmbus.get_status_byte(0x40).unwrap(),
+            StatusByte::OFF | StatusByte::CML
+        );
+        let err = pmbus.get_status_byte(0x40).unwrap_err();
+        assert!(matches!(err, PmbusError::PecMismatch { .. }));
+
+        pmbus.clear_faults(0x40).unwrap();
+        let crc = pec::crc8(&[0x80, 0x03]);
+        assert_eq!(bus.writes().last().unwrap().1, [0x03, crc]);
+    }
+}
@@ -0,0 +1,56 @@
+/// Decoded CAPABILITY register (0x19).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capability {
+    /// Bit 7: the device supports Packet Error Checking.
+    pub pec_supported: bool,
+    /// Bits \[6:5\]: maximum supported bus speed.
+    ///
+    /// The reserved encoding `11` decodes as 100 kHz, the speed every SMBus
+    /// device must support.
+    pub max_speed_khz: u16,
+    /// Bit 4: the device has an SMBALERT# pin and supports the SMBus Alert
+    /// Response Address.
+    pub smbalert_supported: bool,
+}
+
+impl Capability {
+    /// Parse a raw CAPABILITY byte. Bits \[3:0\] are ignored.
+    pub fn from_raw(raw: u8) -> Self {
+        let max_speed_khz = match (raw >> 5) & 0x03 {
+            0b01 => 400,
+            0b10 => 1000,
+            _ => 100,
+        };
+        Self {
+            pec_supported: raw & 0x80 != 0,
+            max_speed_khz,
+            smbalert_supported: raw & 0x10 != 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_common_bytes() {
+        let cap = Capability::from_raw(0xB0);
+        assert!(cap.pec_supported && cap.smbalert_supported);
+        assert_eq!(cap.max_speed_khz, 400);
+
+        let cap = Capability::from_raw(0xD0);
+        assert!(cap.pec_supported && cap.smbalert_supported);
+        assert_eq!(cap.max_speed_khz, 1000);
+
+        let cap = Capability::from_raw(0x00);
+        assert!(!cap.pec_supported && !cap.smbalert_supported);
+        assert_eq!(cap.max_speed_khz, 100);
+    }
+
+    #[test]
+    fn reserved_speed_is_100khz() {
+        assert_eq!(Capability::from_raw(0x60).max_speed_khz, 100);
+        assert_eq!(Capability::from_raw(0x40).max_speed_khz, 1000);
+    }
+}
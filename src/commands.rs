@@ -215,11 +215,439 @@ pub enum CommandCode {
     PmbusCommandExt = 0xFF,
 }
 
+/// How a command's value is transferred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataWidth {
+    /// Send byte: the command code alone (CLEAR_FAULTS, STORE_*/RESTORE_*_ALL).
+    None,
+    /// One data byte.
+    Byte,
+    /// Two data bytes, little-endian.
+    Word,
+    /// SMBus block with a leading count byte.
+    Block,
+    /// Process calls, 4-byte reads and extended commands that don't fit the
+    /// above.
+    Other,
+}
+
 impl CommandCode {
     /// Return the raw u8 command code.
     pub fn code(self) -> u8 {
         self as u8
     }
+
+    /// How this command's value is read and written.
+    ///
+    /// STATUS_MFR_SPECIFIC is reported as a byte, though some devices
+    /// implement it as a word.
+    pub fn data_width(self) -> DataWidth {
+        use CommandCode::*;
+        match self {
+            ClearFaults | StoreDefaultAll | RestoreDefaultAll | StoreUserAll | RestoreUserAll => {
+                DataWidth::None
+            }
+            Page
+            | Operation
+            | OnOffConfig
+            | Phase
+            | WriteProtect
+            | StoreDefaultCode
+            | RestoreDefaultCode
+            | StoreUserCode
+            | RestoreUserCode
+            | Capability
+            | VoutMode
+            | PowerMode
+            | FanConfig12
+            | FanConfig34
+            | VoutOvFaultResponse
+            | VoutUvFaultResponse
+            | IoutOcFaultResponse
+            | IoutOcLvFaultResponse
+            | IoutUcFaultResponse
+            | OtFaultResponse
+            | UtFaultResponse
+            | VinOvFaultResponse
+            | VinUvFaultResponse
+            | IinOcFaultResponse
+            | TonMaxFaultResponse
+            | PoutOpFaultResponse
+            | StatusByte
+            | StatusVout
+            | StatusIout
+            | StatusInput
+            | StatusTemperature
+            | StatusCml
+            | StatusOther
+            | StatusMfrSpecific
+            | StatusFans12
+            | StatusFans34
+            | PmbusRevision
+            | MfrPinAccuracy => DataWidth::Byte,
+            ReadEin | ReadEout | MfrId | MfrModel | MfrRevision | MfrLocation | MfrDate
+            | MfrSerial | AppProfileSupport | MfrEfficiencyLl | MfrEfficiencyHl | IcDeviceId
+            | IcDeviceRev | UserData00 | UserData01 | UserData02 | UserData03 | UserData04
+            | UserData05 | UserData06 | UserData07 | UserData08 | UserData09 | UserData10
+            | UserData11 | UserData12 | UserData13 | UserData14 | UserData15 => DataWidth::Block,
+            PagePlusWrite
+            | PagePlusRead
+            | Query
+            | SmbalertMask
+            | Coefficients
+            | ReadKwhIn
+            | ReadKwhOut
+            | MfrSpecificCommandExt
+            | PmbusCommandExt => DataWidth::Other,
+            _ => DataWidth::Word,
+        }
+    }
+
+    /// Every defined command, in code order.
+    pub const ALL: [Self; 166] = [
+        Self::Page,
+        Self::Operation,
+        Self::OnOffConfig,
+        Self::ClearFaults,
+        Self::Phase,
+        Self::PagePlusWrite,
+        Self::PagePlusRead,
+        Self::ZoneConfig,
+        Self::ZoneActive,
+        Self::WriteProtect,
+        Self::StoreDefaultAll,
+        Self::RestoreDefaultAll,
+        Self::StoreDefaultCode,
+        Self::RestoreDefaultCode,
+        Self::StoreUserAll,
+        Self::RestoreUserAll,
+        Self::StoreUserCode,
+        Self::RestoreUserCode,
+        Self::Capability,
+        Self::Query,
+        Self::SmbalertMask,
+        Self::VoutMode,
+        Self::VoutCommand,
+        Self::VoutTrim,
+        Self::VoutCalOffset,
+        Self::VoutMax,
+        Self::VoutMarginHigh,
+        Self::VoutMarginLow,
+        Self::VoutTransitionRate,
+        Self::VoutDroop,
+        Self::VoutScaleLoop,
+        Self::VoutScaleMonitor,
+        Self::VoutMin,
+        Self::Coefficients,
+        Self::PoutMax,
+        Self::MaxDuty,
+        Self::FrequencySwitch,
+        Self::PowerMode,
+        Self::VinOn,
+        Self::VinOff,
+        Self::Interleave,
+        Self::IoutCalGain,
+        Self::IoutCalOffset,
+        Self::FanConfig12,
+        Self::FanCommand1,
+        Self::FanCommand2,
+        Self::FanConfig34,
+        Self::FanCommand3,
+        Self::FanCommand4,
+        Self::VoutOvFaultLimit,
+        Self::VoutOvFaultResponse,
+        Self::VoutOvWarnLimit,
+        Self::VoutUvWarnLimit,
+        Self::VoutUvFaultLimit,
+        Self::VoutUvFaultResponse,
+        Self::IoutOcFaultLimit,
+        Self::IoutOcFaultResponse,
+        Self::IoutOcLvFaultLimit,
+        Self::IoutOcLvFaultResponse,
+        Self::IoutOcWarnLimit,
+        Self::IoutUcFaultLimit,
+        Self::IoutUcFaultResponse,
+        Self::OtFaultLimit,
+        Self::OtFaultResponse,
+        Self::OtWarnLimit,
+        Self::UtWarnLimit,
+        Self::UtFaultLimit,
+        Self::UtFaultResponse,
+        Self::VinOvFaultLimit,
+        Self::VinOvFaultResponse,
+        Self::VinOvWarnLimit,
+        Self::VinUvWarnLimit,
+        Self::VinUvFaultLimit,
+        Self::VinUvFaultResponse,
+        Self::IinOcFaultLimit,
+        Self::IinOcFaultResponse,
+        Self::IinOcWarnLimit,
+        Self::PowerGoodOn,
+        Self::PowerGoodOff,
+        Self::TonDelay,
+        Self::TonRise,
+        Self::TonMaxFaultLimit,
+        Self::TonMaxFaultResponse,
+        Self::ToffDelay,
+        Self::ToffFall,
+        Self::ToffMaxWarnLimit,
+        Self::PoutOpFaultLimit,
+        Self::PoutOpFaultResponse,
+        Self::PoutOpWarnLimit,
+        Self::PinOpWarnLimit,
+        Self::StatusByte,
+        Self::StatusWord,
+        Self::StatusVout,
+        Self::StatusIout,
+        Self::StatusInput,
+        Self::StatusTemperature,
+        Self::StatusCml,
+        Self::StatusOther,
+        Self::StatusMfrSpecific,
+        Self::StatusFans12,
+        Self::StatusFans34,
+        Self::ReadKwhIn,
+        Self::ReadKwhOut,
+        Self::ReadKwhConfig,
+        Self::ReadEin,
+        Self::ReadEout,
+        Self::ReadVin,
+        Self::ReadIin,
+        Self::ReadVcap,
+        Self::ReadVout,
+        Self::ReadIout,
+        Self::ReadTemperature1,
+        Self::ReadTemperature2,
+        Self::ReadTemperature3,
+        Self::ReadFanSpeed1,
+        Self::ReadFanSpeed2,
+        Self::ReadFanSpeed3,
+        Self::ReadFanSpeed4,
+        Self::ReadDutyCycle,
+        Self::ReadFrequency,
+        Self::ReadPout,
+        Self::ReadPin,
+        Self::PmbusRevision,
+        Self::MfrId,
+        Self::MfrModel,
+        Self::MfrRevision,
+        Self::MfrLocation,
+        Self::MfrDate,
+        Self::MfrSerial,
+        Self::AppProfileSupport,
+        Self::MfrVinMin,
+        Self::MfrVinMax,
+        Self::MfrIinMax,
+        Self::MfrPinMax,
+        Self::MfrVoutMin,
+        Self::MfrVoutMax,
+        Self::MfrIoutMax,
+        Self::MfrPoutMax,
+        Self::MfrTambientMax,
+        Self::MfrTambientMin,
+        Self::MfrEfficiencyLl,
+        Self::MfrEfficiencyHl,
+        Self::MfrPinAccuracy,
+        Self::IcDeviceId,
+        Self::IcDeviceRev,
+        Self::UserData00,
+        Self::UserData01,
+        Self::UserData02,
+        Self::UserData03,
+        Self::UserData04,
+        Self::UserData05,
+        Self::UserData06,
+        Self::UserData07,
+        Self::UserData08,
+        Self::UserData09,
+        Self::UserData10,
+        Self::UserData11,
+        Self::UserData12,
+        Self::UserData13,
+        Self::UserData14,
+        Self::UserData15,
+        Self::MfrMaxTemp1,
+        Self::MfrMaxTemp2,
+        Self::MfrMaxTemp3,
+        Self::MfrSpecificCommandExt,
+        Self::PmbusCommandExt,
+    ];
+
+    /// Look up the command for a raw code; `None` for reserved or
+    /// manufacturer-specific codes.
+    ///
+    /// Also available as `CommandCode::try_from(code)`.
+    pub fn from_u8(code: u8) -> Option<Self> {
+        Some(match code {
+            0x00 => Self::Page,
+            0x01 => Self::Operation,
+            0x02 => Self::OnOffConfig,
+            0x03 => Self::ClearFaults,
+            0x04 => Self::Phase,
+            0x05 => Self::PagePlusWrite,
+            0x06 => Self::PagePlusRead,
+            0x07 => Self::ZoneConfig,
+            0x08 => Self::ZoneActive,
+            0x10 => Self::WriteProtect,
+            0x11 => Self::StoreDefaultAll,
+            0x12 => Self::RestoreDefaultAll,
+            0x13 => Self::StoreDefaultCode,
+            0x14 => Self::RestoreDefaultCode,
+            0x15 => Self::StoreUserAll,
+            0x16 => Self::RestoreUserAll,
+            0x17 => Self::StoreUserCode,
+            0x18 => Self::RestoreUserCode,
+            0x19 => Self::Capability,
+            0x1A => Self::Query,
+            0x1B => Self::SmbalertMask,
+            0x20 => Self::VoutMode,
+            0x21 => Self::VoutCommand,
+            0x22 => Self::VoutTrim,
+            0x23 => Self::VoutCalOffset,
+            0x24 => Self::VoutMax,
+            0x25 => Self::VoutMarginHigh,
+            0x26 => Self::VoutMarginLow,
+            0x27 => Self::VoutTransitionRate,
+            0x28 => Self::VoutDroop,
+            0x29 => Self::VoutScaleLoop,
+            0x2A => Self::VoutScaleMonitor,
+            0x2B => Self::VoutMin,
+            0x30 => Self::Coefficients,
+            0x31 => Self::PoutMax,
+            0x32 => Self::MaxDuty,
+            0x33 => Self::FrequencySwitch,
+            0x34 => Self::PowerMode,
+            0x35 => Self::VinOn,
+            0x36 => Self::VinOff,
+            0x37 => Self::Interleave,
+            0x38 => Self::IoutCalGain,
+            0x39 => Self::IoutCalOffset,
+            0x3A => Self::FanConfig12,
+            0x3B => Self::FanCommand1,
+            0x3C => Self::FanCommand2,
+            0x3D => Self::FanConfig34,
+            0x3E => Self::FanCommand3,
+            0x3F => Self::FanCommand4,
+            0x40 => Self::VoutOvFaultLimit,
+            0x41 => Self::VoutOvFaultResponse,
+            0x42 => Self::VoutOvWarnLimit,
+            0x43 => Self::VoutUvWarnLimit,
+            0x44 => Self::VoutUvFaultLimit,
+            0x45 => Self::VoutUvFaultResponse,
+            0x46 => Self::IoutOcFaultLimit,
+            0x47 => Self::IoutOcFaultResponse,
+            0x48 => Self::IoutOcLvFaultLimit,
+            0x49 => Self::IoutOcLvFaultResponse,
+            0x4A => Self::IoutOcWarnLimit,
+            0x4B => Self::IoutUcFaultLimit,
+            0x4C => Self::IoutUcFaultResponse,
+            0x4F => Self::OtFaultLimit,
+            0x50 => Self::OtFaultResponse,
+            0x51 => Self::OtWarnLimit,
+            0x52 => Self::UtWarnLimit,
+            0x53 => Self::UtFaultLimit,
+            0x54 => Self::UtFaultResponse,
+            0x55 => Self::VinOvFaultLimit,
+            0x56 => Self::VinOvFaultResponse,
+            0x57 => Self::VinOvWarnLimit,
+            0x58 => Self::VinUvWarnLimit,
+            0x59 => Self::VinUvFaultLimit,
+            0x5A => Self::VinUvFaultResponse,
+            0x5B => Self::IinOcFaultLimit,
+            0x5C => Self::IinOcFaultResponse,
+            0x5D => Self::IinOcWarnLimit,
+            0x5E => Self::PowerGoodOn,
+            0x5F => Self::PowerGoodOff,
+            0x60 => Self::TonDelay,
+            0x61 => Self::TonRise,
+            0x62 => Self::TonMaxFaultLimit,
+            0x63 => Self::TonMaxFaultResponse,
+            0x64 => Self::ToffDelay,
+            0x65 => Self::ToffFall,
+            0x66 => Self::ToffMaxWarnLimit,
+            0x68 => Self::PoutOpFaultLimit,
+            0x69 => Self::PoutOpFaultResponse,
+            0x6A => Self::PoutOpWarnLimit,
+            0x6B => Self::PinOpWarnLimit,
+            0x78 => Self::StatusByte,
+            0x79 => Self::StatusWord,
+            0x7A => Self::StatusVout,
+            0x7B => Self::StatusIout,
+            0x7C => Self::StatusInput,
+            0x7D => Self::StatusTemperature,
+            0x7E => Self::StatusCml,
+            0x7F => Self::StatusOther,
+            0x80 => Self::StatusMfrSpecific,
+            0x81 => Self::StatusFans12,
+            0x82 => Self::StatusFans34,
+            0x83 => Self::ReadKwhIn,
+            0x84 => Self::ReadKwhOut,
+            0x85 => Self::ReadKwhConfig,
+            0x86 => Self::ReadEin,
+            0x87 => Self::ReadEout,
+            0x88 => Self::ReadVin,
+            0x89 => Self::ReadIin,
+            0x8A => Self::ReadVcap,
+            0x8B => Self::ReadVout,
+            0x8C => Self::ReadIout,
+            0x8D => Self::ReadTemperature1,
+            0x8E => Self::ReadTemperature2,
+            0x8F => Self::ReadTemperature3,
+            0x90 => Self::ReadFanSpeed1,
+            0x91 => Self::ReadFanSpeed2,
+            0x92 => Self::ReadFanSpeed3,
+            0x93 => Self::ReadFanSpeed4,
+            0x94 => Self::ReadDutyCycle,
+            0x95 => Self::ReadFrequency,
+            0x96 => Self::ReadPout,
+            0x97 => Self::ReadPin,
+            0x98 => Self::PmbusRevision,
+            0x99 => Self::MfrId,
+            0x9A => Self::MfrModel,
+            0x9B => Self::MfrRevision,
+            0x9C => Self::MfrLocation,
+            0x9D => Self::MfrDate,
+            0x9E => Self::MfrSerial,
+            0x9F => Self::AppProfileSupport,
+            0xA0 => Self::MfrVinMin,
+            0xA1 => Self::MfrVinMax,
+            0xA2 => Self::MfrIinMax,
+            0xA3 => Self::MfrPinMax,
+            0xA4 => Self::MfrVoutMin,
+            0xA5 => Self::MfrVoutMax,
+            0xA6 => Self::MfrIoutMax,
+            0xA7 => Self::MfrPoutMax,
+            0xA8 => Self::MfrTambientMax,
+            0xA9 => Self::MfrTambientMin,
+            0xAA => Self::MfrEfficiencyLl,
+            0xAB => Self::MfrEfficiencyHl,
+            0xAC => Self::MfrPinAccuracy,
+            0xAD => Self::IcDeviceId,
+            0xAE => Self::IcDeviceRev,
+            0xB0 => Self::UserData00,
+            0xB1 => Self::UserData01,
+            0xB2 => Self::UserData02,
+            0xB3 => Self::UserData03,
+            0xB4 => Self::UserData04,
+            0xB5 => Self::UserData05,
+            0xB6 => Self::UserData06,
+            0xB7 => Self::UserData07,
+            0xB8 => Self::UserData08,
+            0xB9 => Self::UserData09,
+            0xBA => Self::UserData10,
+            0xBB => Self::UserData11,
+            0xBC => Self::UserData12,
+            0xBD => Self::UserData13,
+            0xBE => Self::UserData14,
+            0xBF => Self::UserData15,
+            0xC0 => Self::MfrMaxTemp1,
+            0xC1 => Self::MfrMaxTemp2,
+            0xC2 => Self::MfrMaxTemp3,
+            0xFE => Self::MfrSpecificCommandExt,
+            0xFF => Self::PmbusCommandExt,
+            _ => return None,
+        })
+    }
 }
 
 impl From<CommandCode> for u8 {
@@ -228,6 +656,15 @@ impl From<CommandCode> for u8 {
     }
 }
 
+impl TryFrom<u8> for CommandCode {
+    /// The code that has no `CommandCode` variant.
+    type Error = u8;
+
+    fn try_from(code: u8) -> Result<Self, u8> {
+        Self::from_u8(code).ok_or(code)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -246,9 +683,38 @@ mod tests {
         assert_eq!(CommandCode::PmbusCommandExt.code(), 0xFF);
     }
 
+    #[test]
+    fn data_widths() {
+        assert_eq!(CommandCode::ClearFaults.data_width(), DataWidth::None);
+        assert_eq!(CommandCode::OnOffConfig.data_width(), DataWidth::Byte);
+        assert_eq!(CommandCode::StatusWord.data_width(), DataWidth::Word);
+        assert_eq!(CommandCode::VoutCommand.data_width(), DataWidth::Word);
+        assert_eq!(CommandCode::MfrMaxTemp3.data_width(), DataWidth::Word);
+        assert_eq!(CommandCode::UserData07.data_width(), DataWidth::Block);
+        assert_eq!(CommandCode::ReadKwhIn.data_width(), DataWidth::Other);
+    }
+
     #[test]
     fn from_u8() {
         let code: u8 = CommandCode::ReadPout.into();
         assert_eq!(code, 0x96);
     }
+
+    #[test]
+    fn from_u8_roundtrips_every_variant() {
+        for cmd in CommandCode::ALL {
+            assert_eq!(CommandCode::from_u8(cmd.code()), Some(cmd));
+            assert_eq!(CommandCode::try_from(cmd.code()), Ok(cmd));
+        }
+        // Every code that decodes is listed in ALL, so the two tables agree.
+        let defined = (0u8..=255).filter_map(CommandCode::from_u8).count();
+        assert_eq!(defined, CommandCode::ALL.len());
+        assert!(
+            CommandCode::ALL
+                .windows(2)
+                .all(|w| w[0].code() < w[1].code())
+        );
+        assert_eq!(CommandCode::from_u8(0x09), None);
+        assert_eq!(CommandCode::try_from(0xD0), Err(0xD0));
+    }
 }
@@ -0,0 +1,63 @@
+use crate::formats::Linear11;
+
+/// Number of load points in an MFR_EFFICIENCY_LL/HL block.
+pub const EFFICIENCY_POINTS: usize = 3;
+
+/// Efficiency characterization read from MFR_EFFICIENCY_LL (0xAA) or
+/// MFR_EFFICIENCY_HL (0xAB).
+///
+/// The 14-byte block holds seven LINEAR11 words: the input voltage the
+/// curve was measured at, then `(output power, efficiency)` pairs for light,
+/// medium and full load.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EfficiencyCurve {
+    /// Input voltage in volts.
+    pub vin: f32,
+    /// `(output power in W, efficiency in %)`, from light to full load.
+    pub points: [(f32, f32); EFFICIENCY_POINTS],
+}
+
+impl EfficiencyCurve {
+    /// Parse the data bytes of the block read (without the count byte).
+    ///
+    /// Returns `None` if fewer than 14 bytes are present.
+    pub fn from_block(data: &[u8]) -> Option<Self> {
+        let word = |i: usize| -> Option<f32> {
+            let bytes = data.get(2 * i..2 * i + 2)?;
+            Some(Linear11::from_raw(u16::from_le_bytes([bytes[0], bytes[1]])).to_f32())
+        };
+        let mut points = [(0.0, 0.0); EFFICIENCY_POINTS];
+        for (i, point) in points.iter_mut().enumerate() {
+            *point = (word(1 + 2 * i)?, word(2 + 2 * i)?);
+        }
+        Some(Self {
+            vin: word(0)?,
+            points,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn le(value: f32) -> [u8; 2] {
+        Linear11::from_f32(value).unwrap().raw().to_le_bytes()
+    }
+
+    #[test]
+    fn parse_curve() {
+        let mut block = [0u8; 14];
+        for (i, v) in [12.0, 5.0, 85.0, 25.0, 92.5, 50.0, 90.0].iter().enumerate() {
+            block[2 * i..2 * i + 2].copy_from_slice(&le(*v));
+        }
+        let curve = EfficiencyCurve::from_block(&block).unwrap();
+        assert_eq!(curve.vin, 12.0);
+        assert_eq!(curve.points, [(5.0, 85.0), (25.0, 92.5), (50.0, 90.0)]);
+    }
+
+    #[test]
+    fn short_block_is_none() {
+        assert!(EfficiencyCurve::from_block(&[0u8; 13]).is_none());
+    }
+}
@@ -0,0 +1,72 @@
+/// Value at which the READ_EIN/READ_EOUT accumulator wraps and bumps the
+/// rollover count.
+pub const ACCUMULATOR_ROLLOVER: u32 = 0x8000;
+
+/// Energy accumulator read from READ_EIN (0x86) or READ_EOUT (0x87).
+///
+/// The 6-byte block holds a 2-byte accumulator of power samples, a 1-byte
+/// rollover count and a 3-byte sample count, all little-endian. The device
+/// adds every READ_PIN/READ_POUT sample to the accumulator, so it is in the
+/// raw units of that register (usually DIRECT-format counts), not LINEAR11.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnergyReading {
+    /// Accumulated power samples, 0..=0x7FFF.
+    pub power_accumulator: u16,
+    /// Times the accumulator wrapped past 0x7FFF. Itself wraps at 255.
+    pub rollover_count: u8,
+    /// Samples accumulated, 24 bits.
+    pub sample_count: u32,
+}
+
+impl EnergyReading {
+    /// Parse the data bytes of the block read (without the count byte).
+    ///
+    /// Returns `None` if fewer than 6 bytes are present.
+    pub fn from_block(data: &[u8]) -> Option<Self> {
+        let bytes: &[u8; 6] = data.get(..6)?.try_into().ok()?;
+        Some(Self {
+            power_accumulator: u16::from_le_bytes([bytes[0], bytes[1]]),
+            rollover_count: bytes[2],
+            sample_count: u32::from_le_bytes([bytes[3], bytes[4], bytes[5], 0]),
+        })
+    }
+
+    /// Total accumulated power, `rollover_count * 0x8000 + power_accumulator`.
+    pub fn total(&self) -> u32 {
+        self.rollover_count as u32 * ACCUMULATOR_ROLLOVER + self.power_accumulator as u32
+    }
+
+    /// Average power over the samples, in the raw units of READ_PIN or
+    /// READ_POUT: `total() / sample_count`.
+    ///
+    /// Decode the result like a raw power reading, e.g. with the device's
+    /// [`DirectCoefficients`](crate::DirectCoefficients). To average over
+    /// an interval, subtract an earlier reading's totals first. The result
+    /// isn't finite if no samples were taken.
+    pub fn average_power(&self) -> f32 {
+        self.total() as f32 / self.sample_count as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_average() {
+        // Round illustrative figures, not taken from a datasheet: accumulator
+        // 0x4000, two rollovers, 4096 samples gives
+        // (2 * 32768 + 16384) / 4096 = 20 counts per sample.
+        let reading = EnergyReading::from_block(&[0x00, 0x40, 0x02, 0x00, 0x10, 0x00]).unwrap();
+        assert_eq!(reading.power_accumulator, 0x4000);
+        assert_eq!(reading.rollover_count, 2);
+        assert_eq!(reading.sample_count, 4096);
+        assert_eq!(reading.total(), 81_920);
+        assert_eq!(reading.average_power(), 20.0);
+    }
+
+    #[test]
+    fn short_block_is_none() {
+        assert!(EnergyReading::from_block(&[0u8; 5]).is_none());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::commands::CommandCode;
+
 /// Errors that can occur during PMBus operations.
 #[derive(Debug)]
 pub enum PmbusError<E> {
@@ -7,6 +9,104 @@ pub enum PmbusError<E> {
     EncodingError,
     /// The device response had an unexpected length.
     InvalidResponseLength,
+    /// DIRECT coefficients that can't be used: exponent R outside the
+    /// supported -8..=8 range, or a zero `m`.
+    CoefficientOutOfRange,
+    /// The PEC byte received from the device did not match the CRC-8
+    /// computed over the whole frame, address and R/W bits included.
+    PecMismatch { expected: u8, received: u8 },
+    /// The dry-run transaction log (or a single recorded entry) is full.
+    RecordOverflow,
+    /// A turn-on threshold was not above its turn-off threshold.
+    InvalidHysteresis,
+    /// The device answered, but not like a PMBus device.
+    NotPmbus,
+    /// VOUT_MODE selects a format the volts helpers can't encode or decode
+    /// (VID, DIRECT or IEEE half); use the raw word accessors instead.
+    UnsupportedVoutMode,
+    /// The output voltage did not reach the target within the timeout.
+    SettleTimeout,
+    /// VOUT_MODE has the relative bit set, so VOUT_COMMAND isn't an
+    /// absolute voltage.
+    RelativeModeUnsupported,
+    /// The command code isn't a status register, or isn't one that
+    /// SMBALERT_MASK can mask.
+    NotStatusCommand,
+    /// A block write payload is longer than the 255 bytes its count byte
+    /// can describe.
+    BlockTooLong,
+    /// The canary register read back after STORE_USER_ALL/RESTORE_USER_ALL
+    /// didn't match the value before the store.
+    StoreFailed,
+    /// The command code isn't a fault/warning limit or threshold.
+    NotALimit,
+    /// Fewer bytes arrived than the command needs.
+    ///
+    /// Raised for block reads whose count byte is below the command's
+    /// minimum, or above the 32 data bytes a block can carry. A HAL that
+    /// returns a short fixed-length read (word, KWH) without an error can't be
+    /// detected here; such HALs must report it as a bus error.
+    ShortRead { expected: usize, got: usize },
+    /// The device still reported BUSY (or kept NACKing) when the wait
+    /// timed out.
+    DeviceBusy,
+    /// STATUS_CML still flagged a communication fault after the configured
+    /// telemetry read retries.
+    CommFault,
+    /// The host bus clock is faster than the maximum speed the device
+    /// advertises in CAPABILITY.
+    BusSpeedTooHigh { configured_khz: u32, max_khz: u16 },
+    /// A status register read back as all ones, which usually means nothing
+    /// drove the bus rather than every fault being set.
+    NoValidResponse,
+    /// The command has no plain byte, word or block read.
+    NotReadable,
+    /// The data format isn't a numeric word format (LINEAR11, ULINEAR16 or
+    /// DIRECT) that can be decoded to `f32`.
+    UnsupportedFormat,
+    /// WRITE_PROTECT was found set again partway through a multi-write
+    /// sequence, so later writes would have been ignored.
+    WriteProtected,
+    /// The fan speed unit (RPM or duty) doesn't match the mode FAN_CONFIG
+    /// selects for that fan.
+    FanModeMismatch,
+    /// SDA was still held low after bus recovery clocked SCL nine times.
+    BusStuck,
+    /// A block read returned a count of zero, which devices use for a
+    /// block command they acknowledge but don't support.
+    EmptyBlock,
+    /// QUERY reported `cmd` as unsupported.
+    CommandUnsupported { cmd: CommandCode },
+    /// QUERY reported a different data format for `cmd` than expected.
+    UnexpectedFormat { cmd: CommandCode },
+    /// The verification register read back after RESTORE_DEFAULT_ALL
+    /// didn't hold its expected default.
+    ResetFailed,
+    /// The requested output voltage is outside the device's
+    /// VOUT_MIN..=VOUT_MAX window.
+    VoltageOutOfBounds { requested: f32, min: f32, max: f32 },
+    /// ON_OFF_CONFIG didn't read back with the OPERATION-control bits set,
+    /// so an OPERATION on command would be ignored.
+    OnOffConfigRejected,
+    /// A group command had more than
+    /// [`MAX_GROUP_OPS`](crate::group::MAX_GROUP_OPS) commands.
+    GroupTooLarge,
+    /// A group command addressed `addr` more than once.
+    DuplicateGroupAddress { addr: u8 },
+}
+
+impl<E> PmbusError<E> {
+    /// Unwrap the underlying bus error, or give `self` back if this error
+    /// didn't come from the bus.
+    ///
+    /// This stands in for `TryFrom<PmbusError<E>> for E`, which the orphan
+    /// rules don't allow for a generic `E`.
+    pub fn into_bus_error(self) -> Result<E, Self> {
+        match self {
+            PmbusError::Bus(e) => Ok(e),
+            other => Err(other),
+        }
+    }
 }
 
 impl<E> From<E> for PmbusError<E> {
@@ -14,3 +114,17 @@ impl<E> From<E> for PmbusError<E> {
         PmbusError::Bus(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_bus_error() {
+        assert_eq!(PmbusError::Bus(7u8).into_bus_error().unwrap(), 7);
+        let err = PmbusError::<u8>::EncodingError
+            .into_bus_error()
+            .unwrap_err();
+        assert!(matches!(err, PmbusError::EncodingError));
+    }
+}
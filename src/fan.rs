@@ -0,0 +1,42 @@
+/// A fan speed command, in the unit FAN_CONFIG selects for the fan.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FanSpeed {
+    /// Speed in revolutions per minute.
+    Rpm(f32),
+    /// PWM duty cycle in percent.
+    DutyPercent(f32),
+}
+
+impl FanSpeed {
+    /// Whether this speed is given in RPM.
+    pub fn is_rpm(self) -> bool {
+        matches!(self, Self::Rpm(_))
+    }
+
+    /// The commanded value, in its own unit.
+    pub fn value(self) -> f32 {
+        match self {
+            Self::Rpm(v) | Self::DutyPercent(v) => v,
+        }
+    }
+}
+
+/// Whether a FAN_CONFIG_1_2/FAN_CONFIG_3_4 byte commands the first
+/// (`second = false`) or second fan of the pair in RPM rather than duty.
+pub(crate) fn commanded_in_rpm(config: u8, second: bool) -> bool {
+    let bit = if second { 0x04 } else { 0x40 };
+    config & bit != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rpm_bit_per_fan() {
+        // Fan 1 in RPM, fan 2 in duty.
+        assert!(commanded_in_rpm(0xC8, false));
+        assert!(!commanded_in_rpm(0xC8, true));
+        assert!(commanded_in_rpm(0x0C, true));
+    }
+}
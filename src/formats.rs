@@ -1,3 +1,8 @@
+use heapless::Vec;
+
+use crate::error::PmbusError;
+use crate::query::DataFormat;
+
 /// Const lookup table for 10^R where R is in [-8, 8].
 const POW10: [f32; 17] = [
     1e-8, 1e-7, 1e-6, 1e-5, 1e-4, 1e-3, 1e-2, 1e-1, 1.0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8,
@@ -10,7 +15,7 @@ fn pow10(r: i8) -> Option<f32> {
 }
 
 /// no_std-compatible rounding (round half away from zero).
-fn round_f32(x: f32) -> f32 {
+pub(crate) fn round_f32(x: f32) -> f32 {
     if x >= 0.0 {
         (x + 0.5) as i32 as f32
     } else {
@@ -18,6 +23,27 @@ fn round_f32(x: f32) -> f32 {
     }
 }
 
+/// Byte order of multi-byte values a device sends outside the SMBus word
+/// and block protocols, such as the 32-bit READ_KWH_IN/OUT counters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ByteOrder {
+    /// Least significant byte first, as SMBus words are sent (default).
+    #[default]
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+impl ByteOrder {
+    /// Assemble a `u32` from four bytes in this order.
+    pub fn u32_from_bytes(self, bytes: [u8; 4]) -> u32 {
+        match self {
+            Self::Little => u32::from_le_bytes(bytes),
+            Self::Big => u32::from_be_bytes(bytes),
+        }
+    }
+}
+
 /// PMBus LINEAR11 data format.
 ///
 /// Encodes a value as `Y * 2^N` where Y is an 11-bit signed mantissa
@@ -37,11 +63,44 @@ impl Linear11 {
         self.0
     }
 
-    /// Decode to `f32`. Value = Y * 2^N.
-    pub fn to_f32(self) -> f32 {
+    /// Split into the exponent N and mantissa Y.
+    fn parts(self) -> (i8, i16) {
         let n = ((self.0 >> 11) as i8) << 3 >> 3; // sign-extend 5 bits
         let y = ((self.0 & 0x07FF) as i16) << 5 >> 5; // sign-extend 11 bits
-        (y as f32) * exp2f(n as i32)
+        (n, y)
+    }
+
+    /// Decode to `f32`. Value = Y * 2^N.
+    pub fn to_f32(self) -> f32 {
+        let (n, y) = self.parts();
+        // A 5-bit N is always within exp2f's range.
+        exp2f(n as i32).map_or(0.0, |scale| (y as f32) * scale)
+    }
+
+    /// Decode to an integer in units of `2^-scale_shift`, i.e.
+    /// `Y << (N + scale_shift)`, without going through `f32`.
+    ///
+    /// Negative total shifts round to nearest; results outside `i32`
+    /// saturate.
+    pub fn to_scaled_int(self, scale_shift: i8) -> i32 {
+        let (n, y) = self.parts();
+        shift_round(y as i64, n as i32 + scale_shift as i32)
+    }
+
+    /// Encode an integer in units of `2^-scale_shift`, picking the smallest
+    /// exponent whose mantissa fits. Inverse of
+    /// [`to_scaled_int`](Self::to_scaled_int).
+    ///
+    /// Returns `None` if the value is too large for LINEAR11.
+    pub fn from_scaled_int(value: i32, scale_shift: i8) -> Option<Self> {
+        (-16i32..=15).find_map(|n| {
+            let y = shift_round(value as i64, -(n + scale_shift as i32));
+            (-1024..=1023).contains(&y).then(|| {
+                let n_bits = (n as u16) & 0x1F;
+                let y_bits = (y as u16) & 0x07FF;
+                Self((n_bits << 11) | y_bits)
+            })
+        })
     }
 
     /// Encode an `f32` value into LINEAR11 format.
@@ -62,13 +121,14 @@ impl Linear11 {
         let mut best_err: f32 = f32::MAX;
 
         for n in -16i8..=15 {
-            let y_f = value / exp2f(n as i32);
+            let scale = exp2f(n as i32)?;
+            let y_f = value / scale;
             let y_rounded = round_f32(y_f) as i32;
             if !(-1024..=1023).contains(&y_rounded) {
                 continue;
             }
             let y = y_rounded as i16;
-            let reconstructed = (y as f32) * exp2f(n as i32);
+            let reconstructed = (y as f32) * scale;
             let err = (value - reconstructed).abs();
             if err < best_err {
                 best_err = err;
@@ -85,6 +145,30 @@ impl Linear11 {
         let y_bits = (best_y as u16) & 0x07FF;
         Some(Self((n_bits << 11) | y_bits))
     }
+
+    /// Encode an `f32` value with a fixed exponent N.
+    ///
+    /// Returns `None` if N is outside -16..=15, or if the rounded mantissa
+    /// doesn't fit in 11 signed bits.
+    pub fn from_f32_with_exponent(value: f32, n: i8) -> Option<Self> {
+        if !value.is_finite() || !(-16..=15).contains(&n) {
+            return None;
+        }
+        let y = round_f32(value / exp2f(n as i32)?) as i32;
+        if !(-1024..=1023).contains(&y) {
+            return None;
+        }
+        Some(Self((((n as u16) & 0x1F) << 11) | ((y as u16) & 0x07FF)))
+    }
+
+    /// Encode `value` with the same exponent N as `template`.
+    ///
+    /// Use this for a limit the device compares against telemetry it
+    /// reports with a fixed exponent: matching N keeps the comparison free
+    /// of extra quantization.
+    pub fn encode_matching(value: f32, template: Linear11) -> Option<Self> {
+        Self::from_f32_with_exponent(value, template.parts().0)
+    }
 }
 
 /// PMBus ULINEAR16 data format.
@@ -106,18 +190,59 @@ impl ULinear16 {
     }
 
     /// Decode to `f32` given the exponent from VOUT_MODE.
+    ///
+    /// An exponent VOUT_MODE can't produce (outside -16..=15) decodes as
+    /// NaN; use [`checked_to_f32`](Self::checked_to_f32) to reject it.
     pub fn to_f32(self, exponent: i8) -> f32 {
-        (self.0 as f32) * exp2f(exponent as i32)
+        self.checked_to_f32(exponent).unwrap_or(f32::NAN)
+    }
+
+    /// Decode to `f32`, or `None` if `exponent` is outside VOUT_MODE's
+    /// 5-bit signed range (-16..=15).
+    pub fn checked_to_f32(self, exponent: i8) -> Option<f32> {
+        if !(-16..=15).contains(&exponent) {
+            return None;
+        }
+        exp2f(exponent as i32).map(|scale| (self.0 as f32) * scale)
+    }
+
+    /// Bundle a raw value with the VOUT_MODE exponent it was read under.
+    ///
+    /// Returns `None` if `exponent` doesn't fit VOUT_MODE's 5-bit signed
+    /// field (-16..=15).
+    pub fn with_exponent(raw: u16, exponent: i8) -> Option<ULinear16WithExp> {
+        (-16..=15)
+            .contains(&exponent)
+            .then_some(ULinear16WithExp { raw, exponent })
+    }
+
+    /// Decode to an integer in units of `2^-scale_shift`, i.e.
+    /// `raw << (exponent + scale_shift)`, without going through `f32`.
+    ///
+    /// Negative total shifts round to nearest; results outside `i32`
+    /// saturate.
+    pub fn to_scaled_int(self, exponent: i8, scale_shift: i8) -> i32 {
+        shift_round(self.0 as i64, exponent as i32 + scale_shift as i32)
+    }
+
+    /// Encode an integer in units of `2^-scale_shift` given the exponent
+    /// from VOUT_MODE. Inverse of [`to_scaled_int`](Self::to_scaled_int).
+    ///
+    /// Returns `None` if the value is negative or doesn't fit in 16 bits.
+    pub fn from_scaled_int(value: i32, exponent: i8, scale_shift: i8) -> Option<Self> {
+        let raw = shift_round(value as i64, -(exponent as i32 + scale_shift as i32));
+        u16::try_from(raw).ok().map(Self)
     }
 
     /// Encode an `f32` into ULINEAR16 given the exponent from VOUT_MODE.
     ///
-    /// Returns `None` if the value cannot be represented.
+    /// Returns `None` if the value cannot be represented, or if the
+    /// exponent is outside -16..=15.
     pub fn from_f32(value: f32, exponent: i8) -> Option<Self> {
-        if !value.is_finite() || value < 0.0 {
+        if !value.is_finite() || value < 0.0 || !(-16..=15).contains(&exponent) {
             return None;
         }
-        let raw_f = value / exp2f(exponent as i32);
+        let raw_f = value / exp2f(exponent as i32)?;
         let raw_rounded = round_f32(raw_f) as u32;
         if raw_rounded > 0xFFFF {
             return None;
@@ -126,6 +251,172 @@ impl ULinear16 {
     }
 }
 
+/// A ULINEAR16 value together with its exponent, so it decodes without
+/// a separately tracked VOUT_MODE. Build with [`ULinear16::with_exponent`],
+/// which keeps the exponent within VOUT_MODE's range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ULinear16WithExp {
+    raw: u16,
+    exponent: i8,
+}
+
+impl ULinear16WithExp {
+    /// Return the raw 16-bit value.
+    pub fn raw(self) -> u16 {
+        self.raw
+    }
+
+    /// Return the VOUT_MODE exponent, within -16..=15.
+    pub fn exponent(self) -> i8 {
+        self.exponent
+    }
+
+    /// Decode to `f32` using the bundled exponent.
+    pub fn to_f32(self) -> f32 {
+        ULinear16::from_raw(self.raw).to_f32(self.exponent)
+    }
+}
+
+/// Encode a signed offset into a ULINEAR16 register as two's complement.
+///
+/// VOUT_TRIM and VOUT_CAL_OFFSET use the VOUT_MODE exponent like
+/// [`ULinear16`], but hold a signed value. Returns `None` if the value is
+/// not finite or doesn't fit in 16 signed bits.
+pub fn encode_signed_ulinear16(value: f32, exponent: i8) -> Option<u16> {
+    if !value.is_finite() {
+        return None;
+    }
+    let raw = round_f32(value / exp2f(exponent as i32)?) as i32;
+    i16::try_from(raw).ok().map(|r| r as u16)
+}
+
+/// Decode a two's-complement ULINEAR16 register as a signed offset.
+///
+/// Inverse of [`encode_signed_ulinear16`]: bit 15 is the sign, so a
+/// negative VOUT_TRIM reads back negative rather than near `2^16 * 2^N`.
+///
+/// As with [`ULinear16::to_f32`], an exponent outside VOUT_MODE's -16..=15
+/// decodes as NaN; use [`checked_decode_signed_ulinear16`] to reject it.
+pub fn decode_signed_ulinear16(raw: u16, exponent: i8) -> f32 {
+    checked_decode_signed_ulinear16(raw, exponent).unwrap_or(f32::NAN)
+}
+
+/// Like [`decode_signed_ulinear16`], but `None` if `exponent` is outside
+/// VOUT_MODE's 5-bit signed range (-16..=15).
+pub fn checked_decode_signed_ulinear16(raw: u16, exponent: i8) -> Option<f32> {
+    if !(-16..=15).contains(&exponent) {
+        return None;
+    }
+    exp2f(exponent as i32).map(|scale| (raw as i16 as f32) * scale)
+}
+
+/// Decode `raw` under every format that needs no device context, for
+/// eyeballing which reading is plausible on an undocumented part.
+///
+/// Always includes LINEAR11; ULINEAR16 when `vout_exp` is given; and U8
+/// when the high byte is zero. DIRECT and VID need coefficients or a
+/// table and are left out.
+pub fn decode_candidates(raw: u16, vout_exp: Option<i8>) -> Vec<(DataFormat, f32), 3> {
+    let mut candidates = Vec::new();
+    let _ = candidates.push((DataFormat::Linear11, Linear11::from_raw(raw).to_f32()));
+    if let Some(exp) = vout_exp {
+        let _ = candidates.push((DataFormat::ULinear16, ULinear16::from_raw(raw).to_f32(exp)));
+    }
+    if raw <= 0xFF {
+        let _ = candidates.push((DataFormat::U8, raw as f32));
+    }
+    candidates
+}
+
+/// IEEE 754 binary16 (half-precision) data format.
+///
+/// Selected for output voltage by [`VoutModeType::IeeeHalf`]: 1 sign bit,
+/// 5 exponent bits with a bias of 15, and 10 mantissa bits. Exponent 0
+/// holds zero and subnormals, exponent 31 infinities and NaN.
+///
+/// [`VoutModeType::IeeeHalf`]: crate::VoutModeType::IeeeHalf
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IeeeHalf(u16);
+
+impl IeeeHalf {
+    /// Largest finite value, 65504.
+    pub const MAX: f32 = 65504.0;
+
+    /// Construct from a raw 16-bit bus value.
+    pub fn from_raw(raw: u16) -> Self {
+        Self(raw)
+    }
+
+    /// Return the raw 16-bit value.
+    pub fn raw(self) -> u16 {
+        self.0
+    }
+
+    /// Decode to `f32`. Every binary16 value, including subnormals,
+    /// infinities and NaN, is exactly representable.
+    pub fn to_f32(self) -> f32 {
+        let negative = self.0 & 0x8000 != 0;
+        let exp = ((self.0 >> 10) & 0x1F) as i32;
+        let mantissa = (self.0 & 0x03FF) as f32;
+        let magnitude = match exp {
+            // Subnormal: M * 2^-24. Both exponents are within exp2f's range.
+            0 => exp2f(-24).map_or(0.0, |scale| mantissa * scale),
+            31 if mantissa == 0.0 => f32::INFINITY,
+            31 => f32::NAN,
+            _ => exp2f(exp - 25).map_or(0.0, |scale| (1024.0 + mantissa) * scale),
+        };
+        if negative { -magnitude } else { magnitude }
+    }
+
+    /// Encode an `f32` value, rounding to nearest with ties to even.
+    ///
+    /// Infinities and NaN encode as their binary16 counterparts. Returns
+    /// `None` if a finite value rounds beyond [`MAX`](Self::MAX), rather
+    /// than silently turning it into infinity.
+    pub fn from_f32(value: f32) -> Option<Self> {
+        let bits = value.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exp = ((bits >> 23) & 0xFF) as i32;
+        let mantissa = bits & 0x007F_FFFF;
+        if exp == 0xFF {
+            let payload = if mantissa == 0 { 0x7C00 } else { 0x7E00 };
+            return Some(Self(sign | payload));
+        }
+        // Unbiased f32 exponent rebiased for binary16.
+        let half_exp = exp - 127 + 15;
+        let magnitude = if half_exp >= 1 {
+            let truncated = ((half_exp as u32) << 10) | (mantissa >> 13);
+            round_ties_even(truncated, mantissa, 13)
+        } else if exp == 0 {
+            // f32 subnormals are far below the binary16 range.
+            0
+        } else {
+            // Subnormal result: shift the full significand into place.
+            let shift = (14 - half_exp) as u32;
+            if shift > 24 {
+                0
+            } else {
+                let significand = mantissa | 0x0080_0000;
+                round_ties_even(significand >> shift, significand, shift)
+            }
+        };
+        // A rounding carry may reach the exponent; past 0x7BFF it overflows.
+        (magnitude < 0x7C00).then_some(Self(sign | magnitude as u16))
+    }
+}
+
+/// Round `truncated` (`full >> shift`) to nearest, ties to even, using the
+/// `shift` discarded low bits of `full`.
+fn round_ties_even(truncated: u32, full: u32, shift: u32) -> u32 {
+    let rem = full & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if rem > half || (rem == half && truncated & 1 == 1) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
 /// PMBus DIRECT data format coefficients.
 ///
 /// Converts between raw register values and real-world units using:
@@ -140,21 +431,42 @@ pub struct DirectCoefficients {
 
 impl DirectCoefficients {
     /// Create new coefficients.
+    ///
+    /// Nothing is validated; use [`DirectCoefficients::try_new`] to reject
+    /// `m = 0` and exponents outside -8..=8 up front.
     pub fn new(m: i16, b: i16, r: i8) -> Self {
         Self { m, b, r }
     }
 
+    /// Create new coefficients, checking that `m` is non-zero and `r` is in
+    /// -8..=8.
+    pub fn try_new<E>(m: i16, b: i16, r: i8) -> Result<Self, PmbusError<E>> {
+        if m == 0 || pow10(r).is_none() {
+            return Err(PmbusError::CoefficientOutOfRange);
+        }
+        Ok(Self { m, b, r })
+    }
+
     /// Decode a raw register value to an `f32`.
-    pub fn to_f32(self, raw: i16) -> f32 {
-        let scale = pow10(-self.r).unwrap_or(1.0);
-        (1.0 / self.m as f32) * ((raw as f32) * scale - self.b as f32)
+    ///
+    /// Returns `None` if `m` is zero or R is outside -8..=8.
+    pub fn to_f32(self, raw: i16) -> Option<f32> {
+        if self.m == 0 {
+            return None;
+        }
+        let scale = pow10(-self.r)?;
+        Some((1.0 / self.m as f32) * ((raw as f32) * scale - self.b as f32))
     }
 
     /// Encode an `f32` value to a raw register value.
     ///
-    /// Returns `None` if the result doesn't fit in i16.
+    /// Returns `None` if `m` is zero, R is outside -8..=8, `value` isn't
+    /// finite, or the result doesn't fit in i16.
     pub fn from_f32(self, value: f32) -> Option<i16> {
-        let scale = pow10(self.r).unwrap_or(1.0);
+        if self.m == 0 || !value.is_finite() {
+            return None;
+        }
+        let scale = pow10(self.r)?;
         let y_f = (self.m as f32 * value + self.b as f32) * scale;
         let y = round_f32(y_f) as i32;
         if y < i16::MIN as i32 || y > i16::MAX as i32 {
@@ -177,19 +489,109 @@ impl DirectCoefficients {
     }
 }
 
-/// Compute 2^n for integer n using bit shifts and division.
-fn exp2f(n: i32) -> f32 {
-    if (0..31).contains(&n) {
-        (1u32 << n) as f32
-    } else if n < 0 && n > -31 {
-        1.0 / (1u32 << (-n)) as f32
-    } else if n >= 31 {
-        f32::MAX
-    } else {
-        f32::MIN_POSITIVE
+/// Which transfer direction a COEFFICIENTS (0x30) query asks about.
+///
+/// The discriminant is the second byte of the query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoeffDirection {
+    /// Coefficients for values the host writes to the device.
+    Write = 0,
+    /// Coefficients for values the device reports.
+    Read = 1,
+}
+
+/// Re-encode a DIRECT register value as LINEAR11, for gateways that present
+/// DIRECT devices to a LINEAR11 host.
+///
+/// Returns `None` if the coefficients are unusable or the value doesn't fit
+/// LINEAR11.
+pub fn transcode_direct_to_linear11(coeffs: DirectCoefficients, raw: i16) -> Option<Linear11> {
+    Linear11::from_f32(coeffs.to_f32(raw)?)
+}
+
+/// Re-encode a LINEAR11 value as a DIRECT register value; the inverse of
+/// [`transcode_direct_to_linear11`].
+///
+/// Returns `None` if the coefficients are unusable or the value doesn't fit
+/// in i16.
+pub fn transcode_linear11_to_direct(coeffs: DirectCoefficients, value: Linear11) -> Option<i16> {
+    coeffs.from_f32(value.to_f32())
+}
+
+/// DIRECT coefficients with the per-sample constants precomputed.
+///
+/// Decoding with [`DirectCoefficients::to_f32`] costs a division and a power
+/// lookup per sample; build one of these once and reuse it when polling
+/// DIRECT telemetry at a high rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PreparedCoefficients {
+    m: f32,
+    b: f32,
+    inv_m: f32,
+    scale_down: f32,
+    scale_up: f32,
+}
+
+impl PreparedCoefficients {
+    /// Precompute `1/m`, `10^-R` and `10^R`.
+    ///
+    /// Returns `None` if `m` is zero or R is outside -8..=8.
+    pub fn new(coeffs: DirectCoefficients) -> Option<Self> {
+        if coeffs.m == 0 {
+            return None;
+        }
+        Some(Self {
+            m: coeffs.m as f32,
+            b: coeffs.b as f32,
+            inv_m: 1.0 / coeffs.m as f32,
+            scale_down: pow10(-coeffs.r)?,
+            scale_up: pow10(coeffs.r)?,
+        })
+    }
+
+    /// Decode a raw two's-complement register value.
+    pub fn decode(&self, raw: i16) -> f32 {
+        self.inv_m * ((raw as f32) * self.scale_down - self.b)
+    }
+
+    /// Encode a value, or `None` if it isn't finite or the result doesn't
+    /// fit in i16.
+    pub fn encode(&self, value: f32) -> Option<i16> {
+        if !value.is_finite() {
+            return None;
+        }
+        let y = round_f32((self.m * value + self.b) * self.scale_up) as i32;
+        i16::try_from(y).ok()
     }
 }
 
+/// Compute `v * 2^shift` in integer space, rounding to nearest for negative
+/// shifts and saturating to `i32`.
+fn shift_round(v: i64, shift: i32) -> i32 {
+    // |v| < 2^32, so shifts of 32 or more always saturate or reach zero.
+    let scaled = if shift >= 32 {
+        v.signum() * i64::MAX
+    } else if shift >= 0 {
+        v << shift
+    } else if shift > -34 {
+        let s = -shift;
+        (v + (1 << (s - 1))) >> s
+    } else {
+        0
+    };
+    scaled.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// Compute 2^n exactly, by building the f32 exponent field directly.
+///
+/// Returns `None` outside the normal `f32` range (-126..=127) instead of
+/// a saturated value that would decode to infinity or garbage.
+fn exp2f(n: i32) -> Option<f32> {
+    (-126..=127)
+        .contains(&n)
+        .then(|| f32::from_bits(((n + 127) as u32) << 23))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,6 +605,14 @@ mod tests {
         assert!((f - 12.5).abs() < 0.01, "expected 12.5, got {f}");
     }
 
+    #[test]
+    fn linear11_negative_mantissa_decode() {
+        // -12.5W encoded as N=-1, Y=-25 → raw = (0x1F << 11) | (-25 & 0x7FF) = 0xFFE7
+        let val = Linear11::from_raw(0xFFE7);
+        assert_eq!(val.to_f32(), -12.5);
+        assert_eq!(Linear11::from_f32(-12.5).unwrap().to_f32(), -12.5);
+    }
+
     #[test]
     fn linear11_encode_decode_roundtrip() {
         for &v in &[0.0, 1.0, -1.0, 12.5, 100.0, 0.125, -500.0, 1023.0] {
@@ -258,7 +668,7 @@ mod tests {
     fn direct_coefficients_decode() {
         // Example: m=1, b=0, R=0 → identity
         let c = DirectCoefficients::new(1, 0, 0);
-        assert_eq!(c.to_f32(100), 100.0);
+        assert_eq!(c.to_f32(100), Some(100.0));
     }
 
     #[test]
@@ -273,10 +683,40 @@ mod tests {
         let c = DirectCoefficients::new(10, 5, 0);
         let raw = c.from_f32(3.0).unwrap(); // Y = 10*3 + 5 = 35
         assert_eq!(raw, 35);
-        let decoded = c.to_f32(35); // X = (35 - 5) / 10 = 3.0
+        let decoded = c.to_f32(35).unwrap(); // X = (35 - 5) / 10 = 3.0
         assert!((decoded - 3.0).abs() < 0.01);
     }
 
+    #[test]
+    fn direct_coefficients_try_new_range() {
+        assert!(DirectCoefficients::try_new::<()>(1, 0, 8).is_ok());
+        assert!(DirectCoefficients::try_new::<()>(1, 0, -8).is_ok());
+        assert!(matches!(
+            DirectCoefficients::try_new::<()>(1, 0, 9),
+            Err(PmbusError::CoefficientOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn direct_coefficients_out_of_range_r_returns_none() {
+        let c = DirectCoefficients::new(1, 0, 12);
+        assert!(c.to_f32(100).is_none());
+        assert!(c.from_f32(1.0).is_none());
+    }
+
+    #[test]
+    fn direct_coefficients_zero_m_returns_none() {
+        let c = DirectCoefficients::new(0, 5, 0);
+        assert!(c.to_f32(100).is_none());
+        assert!(c.from_f32(1.0).is_none());
+        assert!(DirectCoefficients::try_new::<()>(0, 5, 0).is_err());
+        assert!(
+            DirectCoefficients::new(1, 0, 0)
+                .from_f32(f32::NAN)
+                .is_none()
+        );
+    }
+
     #[test]
     fn direct_coefficients_from_response() {
         let data = [0x0A, 0x00, 0x05, 0x00, 0x00]; // m=10, b=5, R=0
@@ -291,6 +731,54 @@ mod tests {
         assert!(DirectCoefficients::from_coefficients_response(&[1, 2, 3]).is_none());
     }
 
+    #[test]
+    fn linear11_scaled_int() {
+        // 12.5 A (N=-1, Y=25) in mA-ish units of 2^-10 A.
+        let v = Linear11::from_raw((0x1F << 11) | 25);
+        assert_eq!(v.to_scaled_int(0), 13); // 12.5 rounds to 13
+        assert_eq!(v.to_scaled_int(1), 25);
+        assert_eq!(v.to_scaled_int(10), 12800);
+        let back = Linear11::from_scaled_int(12800, 10).unwrap();
+        assert_eq!(back.to_scaled_int(10), 12800);
+        assert!((back.to_f32() - 12.5).abs() < f32::EPSILON);
+
+        let neg = Linear11::from_f32(-3.0).unwrap();
+        assert_eq!(neg.to_scaled_int(4), -48);
+        assert_eq!(Linear11::from_scaled_int(-48, 4).unwrap().to_f32(), -3.0);
+
+        // Saturates instead of wrapping.
+        let big = Linear11::from_raw((0x0F << 11) | 0x3FF);
+        assert_eq!(big.to_scaled_int(20), i32::MAX);
+        assert!(Linear11::from_scaled_int(i32::MAX, -20).is_none());
+    }
+
+    #[test]
+    fn ulinear16_scaled_int() {
+        // 1.2 V at exponent -12 is raw 4915; in mV-ish units of 2^-10 V.
+        let v = ULinear16::from_raw(4915);
+        assert_eq!(v.to_scaled_int(-12, 12), 4915);
+        assert_eq!(v.to_scaled_int(-12, 10), 1229);
+        assert_eq!(
+            ULinear16::from_scaled_int(1229, -12, 10).unwrap().raw(),
+            4916
+        );
+        assert!(ULinear16::from_scaled_int(-1, -12, 12).is_none());
+        assert!(ULinear16::from_scaled_int(0x10000, -12, 12).is_none());
+    }
+
+    #[test]
+    fn transcode_between_direct_and_linear11() {
+        // m=10, b=5, R=0: raw 35 is 3.0.
+        let c = DirectCoefficients::new(10, 5, 0);
+        let l = transcode_direct_to_linear11(c, 35).unwrap();
+        assert!((l.to_f32() - 3.0).abs() < f32::EPSILON);
+        assert_eq!(transcode_linear11_to_direct(c, l), Some(35));
+        assert!(transcode_direct_to_linear11(DirectCoefficients::new(0, 0, 0), 35).is_none());
+        // (10 * 1000 + 5) * 10^1 overflows i16.
+        let big = Linear11::from_f32(1000.0).unwrap();
+        assert!(transcode_linear11_to_direct(DirectCoefficients::new(10, 5, 1), big).is_none());
+    }
+
     #[test]
     fn pow10_table() {
         assert!((pow10(0).unwrap() - 1.0).abs() < f32::EPSILON);
@@ -320,6 +808,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn test_from_ulinear16_tps546() {
         let exp: i8 = -12;
         // 1229 * 2^-12 = 0.300048828125 V
@@ -346,4 +835,186 @@ mod tests {
         // 700mV = 0.700V -> round(0.700 / 2^-12) = round(2867.2) = 2867
         assert_eq!(ULinear16::from_f32(0.700, exp).unwrap().raw(), 2867);
     }
+
+    #[test]
+    fn ieee_half_decode() {
+        assert_eq!(IeeeHalf::from_raw(0x0000).to_f32(), 0.0);
+        assert_eq!(IeeeHalf::from_raw(0x3C00).to_f32(), 1.0);
+        assert_eq!(IeeeHalf::from_raw(0xC000).to_f32(), -2.0);
+        assert_eq!(IeeeHalf::from_raw(0x7BFF).to_f32(), IeeeHalf::MAX);
+        // Smallest subnormal, 2^-24.
+        assert_eq!(IeeeHalf::from_raw(0x0001).to_f32(), 1.0 / 16_777_216.0);
+        assert_eq!(IeeeHalf::from_raw(0xFC00).to_f32(), f32::NEG_INFINITY);
+        assert!(IeeeHalf::from_raw(0x7E00).to_f32().is_nan());
+    }
+
+    #[test]
+    fn ieee_half_encode() {
+        assert_eq!(IeeeHalf::from_f32(0.0).unwrap().raw(), 0x0000);
+        assert_eq!(IeeeHalf::from_f32(-0.0).unwrap().raw(), 0x8000);
+        assert_eq!(IeeeHalf::from_f32(1.0).unwrap().raw(), 0x3C00);
+        assert_eq!(IeeeHalf::from_f32(65504.0).unwrap().raw(), 0x7BFF);
+        assert_eq!(
+            IeeeHalf::from_f32(1.0 / 16_777_216.0).unwrap().raw(),
+            0x0001
+        );
+        // 1.2 V lies between two codes and rounds to the nearer one.
+        assert_eq!(IeeeHalf::from_f32(1.2).unwrap().raw(), 0x3CCD);
+        // Ties go to the even mantissa: 1 + 2^-11 rounds down to 1.0.
+        assert_eq!(
+            IeeeHalf::from_f32(1.0 + 1.0 / 2048.0).unwrap().raw(),
+            0x3C00
+        );
+        assert_eq!(IeeeHalf::from_f32(f32::INFINITY).unwrap().raw(), 0x7C00);
+        assert!(IeeeHalf::from_f32(f32::NAN).unwrap().to_f32().is_nan());
+        assert!(IeeeHalf::from_f32(65520.0).is_none());
+        assert_eq!(IeeeHalf::from_f32(1e-10).unwrap().raw(), 0x0000);
+    }
+
+    #[test]
+    fn ieee_half_roundtrip() {
+        for raw in 0u16..=0xFFFF {
+            let value = IeeeHalf::from_raw(raw).to_f32();
+            if value.is_nan() {
+                continue;
+            }
+            assert_eq!(IeeeHalf::from_f32(value).map(IeeeHalf::raw), Some(raw));
+        }
+    }
+
+    #[test]
+    fn exp2f_is_exact_and_bounded() {
+        assert_eq!(exp2f(0), Some(1.0));
+        assert_eq!(exp2f(-9), Some(1.0 / 512.0));
+        assert_eq!(exp2f(127), Some(f32::MAX / (2.0 - f32::EPSILON)));
+        assert_eq!(exp2f(128), None);
+        assert_eq!(exp2f(-127), None);
+        assert_eq!(ULinear16::from_raw(0x0200).checked_to_f32(-9), Some(1.0));
+        assert_eq!(ULinear16::from_raw(0x0200).checked_to_f32(31), None);
+        assert!(ULinear16::from_raw(0x0200).to_f32(100).is_nan());
+        assert_eq!(ULinear16::from_f32(1.0, 100), None);
+    }
+
+    #[test]
+    fn candidates_per_format() {
+        let c = decode_candidates(0x0200, Some(-9));
+        assert_eq!(
+            c.as_slice(),
+            [(DataFormat::Linear11, 512.0), (DataFormat::ULinear16, 1.0)]
+        );
+        let c = decode_candidates(0x0019, None);
+        assert_eq!(
+            c.as_slice(),
+            [(DataFormat::Linear11, 25.0), (DataFormat::U8, 25.0)]
+        );
+    }
+
+    #[test]
+    fn linear11_matching_exponent() {
+        // Telemetry 12.25 V with N = -6; a 13 V limit in the same N.
+        let template = Linear11::from_f32_with_exponent(12.25, -6).unwrap();
+        let limit = Linear11::encode_matching(13.0, template).unwrap();
+        assert_eq!(limit.raw(), 0xD340);
+        assert_eq!(limit.to_f32(), 13.0);
+        // 20 V needs Y = 1280 at N = -6, which doesn't fit.
+        assert_eq!(Linear11::encode_matching(20.0, template), None);
+        assert_eq!(Linear11::from_f32_with_exponent(1.0, 16), None);
+    }
+
+    #[test]
+    fn ulinear16_with_exponent() {
+        let v = ULinear16::with_exponent(0x0200, -9).unwrap();
+        assert_eq!(v.to_f32(), 1.0);
+        assert_eq!((v.raw(), v.exponent()), (0x0200, -9));
+        assert_eq!(ULinear16::with_exponent(0x0200, 16), None);
+        assert_eq!(ULinear16::with_exponent(0x0200, -17), None);
+    }
+
+    #[test]
+    fn signed_ulinear16_encode() {
+        // exponent -9: 1 LSB = 1/512 V
+        assert_eq!(encode_signed_ulinear16(0.05, -9), Some(26));
+        assert_eq!(encode_signed_ulinear16(-0.05, -9), Some(0xFFE6));
+        assert_eq!(encode_signed_ulinear16(64.0, -9), None);
+        assert_eq!(encode_signed_ulinear16(-64.0, -9), Some(0x8000));
+        assert_eq!(encode_signed_ulinear16(f32::NAN, -9), None);
+    }
+
+    #[test]
+    fn signed_ulinear16_decode() {
+        assert_eq!(decode_signed_ulinear16(26, -9), 26.0 / 512.0);
+        assert_eq!(decode_signed_ulinear16(0xFFE6, -9), -26.0 / 512.0);
+        assert_eq!(decode_signed_ulinear16(0x8000, -9), -64.0);
+        for v in [-0.05f32, 0.0, 0.125, 63.0] {
+            let raw = encode_signed_ulinear16(v, -9).unwrap();
+            assert!((decode_signed_ulinear16(raw, -9) - v).abs() <= 1.0 / 1024.0);
+        }
+        assert_eq!(
+            checked_decode_signed_ulinear16(0xFFE6, -9),
+            Some(-26.0 / 512.0)
+        );
+        assert_eq!(checked_decode_signed_ulinear16(26, 16), None);
+        assert!(decode_signed_ulinear16(26, -17).is_nan());
+    }
+
+    #[test]
+    fn prepared_coefficients_match_direct() {
+        let c = DirectCoefficients::new(3, -5, -1);
+        let p = PreparedCoefficients::new(c).unwrap();
+        for raw in [-3000i16, -1, 0, 7, 1234, i16::MAX] {
+            assert_eq!(p.decode(raw), c.to_f32(raw).unwrap());
+        }
+        assert_eq!(p.encode(12.0), c.from_f32(12.0));
+        assert_eq!(p.encode(1.0e6), None);
+        assert!(PreparedCoefficients::new(DirectCoefficients::new(1, 0, 9)).is_none());
+        assert!(PreparedCoefficients::new(DirectCoefficients::new(0, 0, 0)).is_none());
+    }
+}
+
+#[cfg(all(test, feature = "proptest"))]
+mod proptests {
+    extern crate std;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Largest magnitude LINEAR11 can hold: 1023 * 2^15.
+    const LINEAR11_MAX: f32 = 1023.0 * 32768.0;
+
+    proptest! {
+        #[test]
+        fn linear11_raw_value_roundtrip(raw in any::<u16>()) {
+            // Several raw words can decode to the same value, so compare values.
+            let x = Linear11::from_raw(raw).to_f32();
+            let back = Linear11::from_f32(x).unwrap();
+            prop_assert_eq!(back.to_f32(), x);
+        }
+
+        #[test]
+        fn linear11_value_within_half_lsb(x in -LINEAR11_MAX..LINEAR11_MAX) {
+            let decoded = Linear11::from_f32(x).unwrap().to_f32();
+            // Y has 10 magnitude bits, and the smallest step is 2^-16.
+            let tol = x.abs() / 1024.0 + exp2f(-17).unwrap();
+            prop_assert!((decoded - x).abs() <= tol, "{} -> {}", x, decoded);
+        }
+
+        #[test]
+        fn ulinear16_raw_roundtrip(raw in any::<u16>(), exp in -16i8..=15) {
+            let x = ULinear16::from_raw(raw).to_f32(exp);
+            prop_assert_eq!(ULinear16::from_f32(x, exp).unwrap().raw(), raw);
+        }
+
+        #[test]
+        fn ulinear16_value_within_half_lsb(frac in 0.0f32..=1.0, exp in -16i8..=15) {
+            let lsb = exp2f(exp as i32).unwrap();
+            let x = frac * 65535.0 * lsb;
+            let decoded = ULinear16::from_f32(x, exp).unwrap().to_f32(exp);
+            prop_assert!((decoded - x).abs() <= lsb / 2.0 + x * f32::EPSILON);
+        }
+
+        #[test]
+        fn ulinear16_rejects_negative(x in -1.0e6f32..-1.0e-6, exp in -16i8..=15) {
+            prop_assert!(ULinear16::from_f32(x, exp).is_none());
+        }
+    }
 }
@@ -0,0 +1,23 @@
+/// A bus that can address several devices in one transfer, as the PMBus
+/// group command protocol needs: `START addr₁ W bytes₁ Sr addr₂ W bytes₂
+/// … STOP`, with a single STOP at the end.
+///
+/// `embedded-hal`'s `I2c::transaction` only ever talks to one address, so
+/// this is implemented on top of the platform's I2C driver, typically by
+/// generating the repeated STARTs by hand. Used by
+/// [`group_command`](crate::PmbusAdaptor::group_command).
+#[allow(async_fn_in_trait)]
+pub trait GroupCommandBus {
+    type Error;
+
+    /// Send each `(7-bit address, bytes)` frame in order, separated by
+    /// repeated STARTs, and end with one STOP. Return an error if any
+    /// address or byte is NACKed.
+    async fn write_group(&mut self, frames: &[(u8, &[u8])]) -> Result<(), Self::Error>;
+}
+
+/// Most commands one group command can carry.
+pub const MAX_GROUP_OPS: usize = 8;
+
+/// Command code, up to 33 data bytes (a block count and 32 bytes) and PEC.
+pub(crate) const MAX_FRAME_LEN: usize = 35;
@@ -0,0 +1,191 @@
+use heapless::Vec;
+
+/// Parsed PMBUS_REVISION register (0x98).
+///
+/// Bits\[7:4\] carry the Part I revision and bits\[3:0\] the Part II
+/// revision, each encoded as the minor version of 1.x (0 = 1.0, 4 = 1.4).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PmbusRevision {
+    pub part_i: u8,
+    pub part_ii: u8,
+}
+
+impl PmbusRevision {
+    /// Parse a raw PMBUS_REVISION byte.
+    pub fn from_raw(raw: u8) -> Self {
+        Self {
+            part_i: raw >> 4,
+            part_ii: raw & 0x0F,
+        }
+    }
+
+    /// Encode back to a raw register byte.
+    pub fn to_raw(self) -> u8 {
+        ((self.part_i & 0x0F) << 4) | (self.part_ii & 0x0F)
+    }
+
+    /// Return `true` if both nibbles name a published revision (1.0 to 1.5).
+    ///
+    /// A floating bus reads back 0xFF and most non-PMBus SMBus peripherals
+    /// return values with an out-of-range nibble, so this is a cheap way to
+    /// tell PMBus devices apart while scanning.
+    pub fn is_plausible(self) -> bool {
+        self.part_i <= 5 && self.part_ii <= 5
+    }
+
+    /// Part I revision as `(major, minor)`, e.g. `(1, 3)` for 1.3.
+    pub fn part_i_version(self) -> (u8, u8) {
+        (1, self.part_i)
+    }
+
+    /// Part II revision as `(major, minor)`, e.g. `(1, 3)` for 1.3.
+    pub fn part_ii_version(self) -> (u8, u8) {
+        (1, self.part_ii)
+    }
+}
+
+/// Payload of an identification block read (MFR_ID, MFR_MODEL, ...).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdString(Vec<u8, 32>);
+
+impl IdString {
+    /// Build from the data bytes of a block read (without the count byte).
+    ///
+    /// Returns `None` for an empty block, which devices use to signal that
+    /// the field isn't populated.
+    pub fn from_block(data: &[u8]) -> Option<Self> {
+        let mut end = data.len();
+        while end > 0 && matches!(data[end - 1], 0x00 | b' ') {
+            end -= 1;
+        }
+        if end == 0 {
+            return None;
+        }
+        Vec::from_slice(&data[..end]).ok().map(Self)
+    }
+
+    /// The raw bytes, with trailing NUL/space padding removed.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// The field as text, if every byte is printable ASCII.
+    pub fn as_str(&self) -> Option<&str> {
+        if self.0.iter().all(|b| (0x20..0x7F).contains(b)) {
+            core::str::from_utf8(&self.0).ok()
+        } else {
+            None
+        }
+    }
+}
+
+/// Application profiles reported by APP_PROFILE_SUPPORT (0x9F).
+///
+/// Each data byte of the block is one profile identifier; the identifiers
+/// are assigned by the individual PMBus application profile specifications
+/// (server, telecom, ...). Duplicates collapse, and order is not kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AppProfiles([u32; 8]);
+
+impl AppProfiles {
+    /// Build from the data bytes of the block read (without the count byte).
+    pub fn from_block(data: &[u8]) -> Self {
+        let mut set = [0u32; 8];
+        for &id in data {
+            set[(id >> 5) as usize] |= 1 << (id & 0x1F);
+        }
+        Self(set)
+    }
+
+    /// Return `true` if the device supports profile `id`.
+    pub fn contains(&self, id: u8) -> bool {
+        self.0[(id >> 5) as usize] & (1 << (id & 0x1F)) != 0
+    }
+
+    /// Number of distinct profiles.
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Return `true` if no profile is reported.
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&w| w == 0)
+    }
+
+    /// Iterate over the profile identifiers in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..=255u8).filter(|&id| self.contains(id))
+    }
+}
+
+/// Identification registers of a PMBus device.
+///
+/// Fields the device doesn't support (NACK or empty block) are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceIdentity {
+    pub pmbus_revision: PmbusRevision,
+    pub mfr_id: Option<IdString>,
+    pub mfr_model: Option<IdString>,
+    pub mfr_revision: Option<IdString>,
+    pub mfr_serial: Option<IdString>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pmbus_revision_nibbles() {
+        let rev = PmbusRevision::from_raw(0x33);
+        assert_eq!(rev.part_i_version(), (1, 3));
+        assert_eq!(rev.part_ii_version(), (1, 3));
+        assert_eq!(rev.to_raw(), 0x33);
+
+        let rev = PmbusRevision::from_raw(0x42);
+        assert_eq!(rev.part_i, 4);
+        assert_eq!(rev.part_ii, 2);
+    }
+
+    #[test]
+    fn pmbus_revision_plausibility() {
+        assert!(PmbusRevision::from_raw(0x00).is_plausible());
+        assert!(PmbusRevision::from_raw(0x44).is_plausible());
+        assert!(!PmbusRevision::from_raw(0xFF).is_plausible());
+        assert!(!PmbusRevision::from_raw(0x4A).is_plausible());
+    }
+
+    #[test]
+    fn app_profiles_set() {
+        let p = AppProfiles::from_block(&[0x21, 0x01, 0x21, 0xFF]);
+        assert_eq!(p.len(), 3);
+        assert!(p.contains(0x01) && p.contains(0x21) && p.contains(0xFF));
+        assert!(!p.contains(0x02));
+        let mut it = p.iter();
+        assert_eq!(
+            (it.next(), it.next(), it.next()),
+            (Some(0x01), Some(0x21), Some(0xFF))
+        );
+        assert_eq!(it.next(), None);
+        assert!(AppProfiles::from_block(&[]).is_empty());
+    }
+
+    #[test]
+    fn id_string_ascii() {
+        let s = IdString::from_block(b"TI\0\0").unwrap();
+        assert_eq!(s.as_str(), Some("TI"));
+        assert_eq!(s.as_bytes(), b"TI");
+    }
+
+    #[test]
+    fn id_string_binary() {
+        let s = IdString::from_block(&[0x54, 0x01, 0xFF]).unwrap();
+        assert_eq!(s.as_str(), None);
+        assert_eq!(s.as_bytes(), &[0x54, 0x01, 0xFF]);
+    }
+
+    #[test]
+    fn id_string_empty_is_none() {
+        assert!(IdString::from_block(&[]).is_none());
+        assert!(IdString::from_block(b"  \0").is_none());
+    }
+}
@@ -0,0 +1,62 @@
+/// Decoded INTERLEAVE (0x37) word for multiphase and paralleled converters.
+///
+/// Layout: bits \[11:8\] group ID, \[7:4\] number of units in the group,
+/// \[3:0\] this unit's position in the group. Each field is 0..=15; bits
+/// \[15:12\] are reserved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Interleave {
+    /// Identifies the interleaving group this unit belongs to.
+    pub group_id: u8,
+    /// Number of units (phases) in the group.
+    pub group_count: u8,
+    /// Position of this unit within the group, starting at 0.
+    pub phase_position: u8,
+}
+
+impl Interleave {
+    /// Parse a raw INTERLEAVE word. Reserved bits are ignored.
+    pub fn from_raw(raw: u16) -> Self {
+        Self {
+            group_id: ((raw >> 8) & 0x0F) as u8,
+            group_count: ((raw >> 4) & 0x0F) as u8,
+            phase_position: (raw & 0x0F) as u8,
+        }
+    }
+
+    /// Encode to a raw INTERLEAVE word.
+    ///
+    /// Returns `None` if any field is above 15.
+    pub fn to_raw(self) -> Option<u16> {
+        if self.group_id > 0x0F || self.group_count > 0x0F || self.phase_position > 0x0F {
+            return None;
+        }
+        Some(
+            (self.group_id as u16) << 8
+                | (self.group_count as u16) << 4
+                | self.phase_position as u16,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let il = Interleave::from_raw(0xF243);
+        assert_eq!(il.group_id, 2);
+        assert_eq!(il.group_count, 4);
+        assert_eq!(il.phase_position, 3);
+        assert_eq!(il.to_raw(), Some(0x0243));
+    }
+
+    #[test]
+    fn out_of_range_field_is_rejected() {
+        let il = Interleave {
+            group_count: 16,
+            ..Default::default()
+        };
+        assert_eq!(il.to_raw(), None);
+    }
+}
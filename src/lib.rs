@@ -1,21 +1,92 @@
 #![no_std]
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod capability;
 pub mod commands;
+mod crc32;
+pub mod efficiency;
+#[cfg(feature = "energy")]
+pub mod energy;
 pub mod error;
+#[cfg(feature = "fans")]
+pub mod fan;
 pub mod formats;
+pub mod group;
+pub mod identity;
+pub mod interleave;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod operation;
+pub mod pec;
+pub mod protection;
+pub mod query;
+pub mod rail;
+#[cfg(feature = "record")]
+pub mod record;
+pub mod recovery;
 pub mod status;
+#[cfg(feature = "limits")]
+pub mod timing;
+pub mod transaction;
+pub mod vid;
 pub mod vout_mode;
 
-use embedded_hal_async::i2c::I2c;
-use heapless::Vec;
+#[cfg(test)]
+mod mock;
+
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::{Error as _, ErrorKind, I2c, Operation};
+use heapless::{FnvIndexMap, Vec};
 use smbus_adapter::SmbusAdaptor;
 
-pub use commands::CommandCode;
+use formats::{checked_decode_signed_ulinear16, encode_signed_ulinear16};
+
+#[cfg(feature = "blocking")]
+pub use blocking::PmbusAdaptorBlocking;
+pub use capability::Capability;
+pub use commands::{CommandCode, DataWidth};
+pub use efficiency::EfficiencyCurve;
+#[cfg(feature = "energy")]
+pub use energy::EnergyReading;
 pub use error::PmbusError;
-pub use formats::{DirectCoefficients, Linear11, ULinear16};
+#[cfg(feature = "fans")]
+pub use fan::FanSpeed;
+pub use formats::{
+    ByteOrder, CoeffDirection, DirectCoefficients, IeeeHalf, Linear11, PreparedCoefficients,
+    ULinear16, ULinear16WithExp,
+};
+pub use group::GroupCommandBus;
+pub use identity::{AppProfiles, DeviceIdentity, IdString, PmbusRevision};
+pub use interleave::Interleave;
+#[cfg(feature = "metrics")]
+pub use metrics::Metrics;
+pub use operation::{MarginDir, MarginFaultAction};
+pub use pec::{Pec, PecMode, PecPolicy};
+pub use protection::{FaultAction, FaultResponse, FaultResponses, Protection};
+pub use query::{DataFormat, QueryResponse};
+#[cfg(feature = "limits")]
+pub use rail::RailConfig;
+pub use rail::{CurrentDirection, RailTelemetry, VoutPair};
+#[cfg(feature = "record")]
+pub use record::RecordedWrite;
+pub use recovery::BusRecoveryPins;
 pub use status::*;
+#[cfg(feature = "limits")]
+pub use timing::TimingLimits;
+pub use transaction::{Response, Transaction};
+pub use vid::{VidTable, vid_to_volts, volts_to_vid};
 pub use vout_mode::{VoutMode, VoutModeType};
 
+/// Number of device addresses tracked by the adapter's per-address caches.
+///
+/// Inserts into a full cache are ignored, so further addresses simply go
+/// uncached and are read from the device each time.
+const CACHE_CAPACITY: usize = 8;
+
+/// PAGE value that addresses every page of a device at once.
+pub const PAGE_ALL: u8 = 0xFF;
+
 // ---------------------------------------------------------------------------
 // Macros to generate repetitive PMBus command methods
 // ---------------------------------------------------------------------------
@@ -23,7 +94,7 @@ pub use vout_mode::{VoutMode, VoutModeType};
 /// Generate a send-byte command (no data payload).
 macro_rules! pmbus_send_byte {
     ($name:ident, $cmd:ident) => {
-        pub async fn $name(&mut self, addr: u8) -> Result<(), BUS::Error> {
+        pub async fn $name(&mut self, addr: u8) -> Result<(), PmbusError<BUS::Error>> {
             self.send_cmd(addr, CommandCode::$cmd).await
         }
     };
@@ -32,10 +103,10 @@ macro_rules! pmbus_send_byte {
 /// Generate read-byte and write-byte pair.
 macro_rules! pmbus_byte_rw {
     ($set:ident, $get:ident, $cmd:ident) => {
-        pub async fn $set(&mut self, addr: u8, data: u8) -> Result<(), BUS::Error> {
+        pub async fn $set(&mut self, addr: u8, data: u8) -> Result<(), PmbusError<BUS::Error>> {
             self.write_cmd_byte(addr, CommandCode::$cmd, data).await
         }
-        pub async fn $get(&mut self, addr: u8) -> Result<u8, BUS::Error> {
+        pub async fn $get(&mut self, addr: u8) -> Result<u8, PmbusError<BUS::Error>> {
             self.read_cmd_byte(addr, CommandCode::$cmd).await
         }
     };
@@ -44,7 +115,7 @@ macro_rules! pmbus_byte_rw {
 /// Generate write-byte only.
 macro_rules! pmbus_write_byte_only {
     ($name:ident, $cmd:ident) => {
-        pub async fn $name(&mut self, addr: u8, data: u8) -> Result<(), BUS::Error> {
+        pub async fn $name(&mut self, addr: u8, data: u8) -> Result<(), PmbusError<BUS::Error>> {
             self.write_cmd_byte(addr, CommandCode::$cmd, data).await
         }
     };
@@ -53,40 +124,101 @@ macro_rules! pmbus_write_byte_only {
 /// Generate read-byte only.
 macro_rules! pmbus_read_byte_only {
     ($name:ident, $cmd:ident) => {
-        pub async fn $name(&mut self, addr: u8) -> Result<u8, BUS::Error> {
+        pub async fn $name(&mut self, addr: u8) -> Result<u8, PmbusError<BUS::Error>> {
             self.read_cmd_byte(addr, CommandCode::$cmd).await
         }
     };
 }
 
 /// Generate read-word and write-word pair.
+///
+/// The `paged:` form also generates variants taking a `page` that is
+/// selected (via the page cache) before the command is issued.
 macro_rules! pmbus_word_rw {
     ($set:ident, $get:ident, $cmd:ident) => {
-        pub async fn $set(&mut self, addr: u8, data: u16) -> Result<(), BUS::Error> {
+        pub async fn $set(&mut self, addr: u8, data: u16) -> Result<(), PmbusError<BUS::Error>> {
+            self.write_cmd_word(addr, CommandCode::$cmd, data).await
+        }
+        pub async fn $get(&mut self, addr: u8) -> Result<u16, PmbusError<BUS::Error>> {
+            self.read_cmd_word(addr, CommandCode::$cmd).await
+        }
+    };
+    ($set:ident, $get:ident, $cmd:ident, paged: $set_paged:ident, $get_paged:ident) => {
+        pmbus_word_rw!($set, $get, $cmd);
+        pub async fn $set_paged(
+            &mut self,
+            addr: u8,
+            page: u8,
+            data: u16,
+        ) -> Result<(), PmbusError<BUS::Error>> {
+            self.select_page(addr, page).await?;
             self.write_cmd_word(addr, CommandCode::$cmd, data).await
         }
-        pub async fn $get(&mut self, addr: u8) -> Result<u16, BUS::Error> {
+        pub async fn $get_paged(
+            &mut self,
+            addr: u8,
+            page: u8,
+        ) -> Result<u16, PmbusError<BUS::Error>> {
+            self.select_page(addr, page).await?;
             self.read_cmd_word(addr, CommandCode::$cmd).await
         }
     };
 }
 
-/// Generate read-word only.
+/// Generate read-word only, optionally with a paged variant.
 macro_rules! pmbus_read_word_only {
     ($name:ident, $cmd:ident) => {
-        pub async fn $name(&mut self, addr: u8) -> Result<u16, BUS::Error> {
-            self.read_cmd_word(addr, CommandCode::$cmd).await
+        pub async fn $name(&mut self, addr: u8) -> Result<u16, PmbusError<BUS::Error>> {
+            self.read_telemetry_word(addr, CommandCode::$cmd).await
+        }
+    };
+    ($name:ident, $cmd:ident, paged: $paged:ident) => {
+        pmbus_read_word_only!($name, $cmd);
+        pub async fn $paged(&mut self, addr: u8, page: u8) -> Result<u16, PmbusError<BUS::Error>> {
+            self.select_page(addr, page).await?;
+            self.read_telemetry_word(addr, CommandCode::$cmd).await
+        }
+    };
+}
+
+/// Generate `$name`, a read-word decoded from LINEAR11 to `f32`, and
+/// `$alias`, a callable forwarder to it.
+macro_rules! pmbus_read_linear11 {
+    ($name:ident, $alias:ident, $cmd:ident) => {
+        pub async fn $name(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+            let raw = self.read_telemetry_word(addr, CommandCode::$cmd).await?;
+            Ok(Linear11::from_raw(raw).to_f32())
+        }
+        #[doc = concat!("Same as [`", stringify!($name), "`](Self::", stringify!($name), ").")]
+        pub async fn $alias(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+            self.$name(addr).await
+        }
+    };
+}
+
+/// Generate a read-word/write-word pair that converts LINEAR11 to/from `f32`.
+macro_rules! pmbus_linear11_rw {
+    ($set:ident, $get:ident, $cmd:ident) => {
+        pub async fn $set(&mut self, addr: u8, value: f32) -> Result<(), PmbusError<BUS::Error>> {
+            let raw = Linear11::from_f32(value).ok_or(PmbusError::EncodingError)?;
+            self.write_cmd_word(addr, CommandCode::$cmd, raw.raw())
+                .await
+        }
+        pub async fn $get(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+            let raw = self.read_cmd_word(addr, CommandCode::$cmd).await?;
+            Ok(Linear11::from_raw(raw).to_f32())
         }
     };
 }
 
 /// Generate block read and block write pair.
+#[cfg_attr(not(feature = "identification"), allow(unused_macros))]
 macro_rules! pmbus_block_rw {
     ($set:ident, $get:ident, $cmd:ident) => {
-        pub async fn $set(&mut self, addr: u8, data: &[u8]) -> Result<(), BUS::Error> {
+        pub async fn $set(&mut self, addr: u8, data: &[u8]) -> Result<(), PmbusError<BUS::Error>> {
             self.block_write_cmd(addr, CommandCode::$cmd, data).await
         }
-        pub async fn $get(&mut self, addr: u8) -> Result<Vec<u8, 32>, BUS::Error> {
+        pub async fn $get(&mut self, addr: u8) -> Result<Vec<u8, 33>, PmbusError<BUS::Error>> {
             self.block_read_cmd(addr, CommandCode::$cmd).await
         }
     };
@@ -95,12 +227,38 @@ macro_rules! pmbus_block_rw {
 /// Generate block read only.
 macro_rules! pmbus_block_read_only {
     ($name:ident, $cmd:ident) => {
-        pub async fn $name(&mut self, addr: u8) -> Result<Vec<u8, 32>, BUS::Error> {
+        pub async fn $name(&mut self, addr: u8) -> Result<Vec<u8, 33>, PmbusError<BUS::Error>> {
             self.block_read_cmd(addr, CommandCode::$cmd).await
         }
     };
 }
 
+/// Generate a setter/getter pair for a fault limit and its response, only
+/// with the `limits` feature.
+macro_rules! pmbus_protection {
+    ($set:ident, $get:ident, $limit:ident, $response:ident) => {
+        #[cfg(feature = "limits")]
+        pub async fn $set(
+            &mut self,
+            addr: u8,
+            protection: &Protection,
+        ) -> Result<(), PmbusError<BUS::Error>> {
+            self.set_protection(
+                addr,
+                CommandCode::$limit,
+                CommandCode::$response,
+                protection,
+            )
+            .await
+        }
+        #[cfg(feature = "limits")]
+        pub async fn $get(&mut self, addr: u8) -> Result<Protection, PmbusError<BUS::Error>> {
+            self.get_protection(addr, CommandCode::$limit, CommandCode::$response)
+                .await
+        }
+    };
+}
+
 // ---------------------------------------------------------------------------
 // PmbusAdaptor
 // ---------------------------------------------------------------------------
@@ -109,14 +267,78 @@ macro_rules! pmbus_block_read_only {
 ///
 /// Provides typed methods for every standard PMBus 1.4 command. The device
 /// address is passed per-call (not stored), matching the smbus-adapter pattern.
+///
+/// # Cancellation
+///
+/// Dropping a method's future (e.g. on a timeout) may abandon an I2C
+/// transaction half-way. The adapter notices this on the next call and runs
+/// [`recover_bus`](Self::recover_bus) against the interrupted address before
+/// issuing anything else. The cached PAGE of that address is discarded since
+/// a PAGE write may not have landed.
 pub struct PmbusAdaptor<BUS: I2c> {
     smbus: SmbusAdaptor<BUS>,
+    auto_clear: AutoClear,
+    pec_mode: PecMode,
+    pec_required: fn(CommandCode) -> bool,
+    pec_policy: PecPolicy,
+    pec_mismatches: u32,
+    invert_iout: bool,
+    reject_all_ones: bool,
+    cml_retries: u8,
+    #[cfg(feature = "energy")]
+    kwh_byte_order: ByteOrder,
+    store_canary: Option<CommandCode>,
+    sync_frequency_code: Option<u8>,
+    pages: FnvIndexMap<u8, u8, CACHE_CAPACITY>,
+    mfr_status_word: FnvIndexMap<u8, bool, CACHE_CAPACITY>,
+    fans_34: FnvIndexMap<u8, bool, CACHE_CAPACITY>,
+    vout_modes: FnvIndexMap<u8, VoutMode, CACHE_CAPACITY>,
+    in_flight: Option<u8>,
+    #[cfg(feature = "record")]
+    recording: bool,
+    #[cfg(feature = "record")]
+    live_pages: FnvIndexMap<u8, u8, CACHE_CAPACITY>,
+    #[cfg(feature = "record")]
+    live_vout_modes: FnvIndexMap<u8, VoutMode, CACHE_CAPACITY>,
+    #[cfg(feature = "record")]
+    log: Vec<RecordedWrite, { record::LOG_CAPACITY }>,
+    #[cfg(feature = "metrics")]
+    metrics: Metrics,
 }
 
 impl<BUS: I2c + 'static> PmbusAdaptor<BUS> {
     /// Create a new PMBus adapter wrapping the given SMBus adapter.
     pub fn new(smbus: SmbusAdaptor<BUS>) -> Self {
-        Self { smbus }
+        Self {
+            smbus,
+            auto_clear: AutoClear::Off,
+            pec_mode: PecMode::Off,
+            pec_required: pec::default_pec_required,
+            pec_policy: PecPolicy::Fail,
+            pec_mismatches: 0,
+            invert_iout: false,
+            reject_all_ones: false,
+            cml_retries: 0,
+            #[cfg(feature = "energy")]
+            kwh_byte_order: ByteOrder::Little,
+            store_canary: None,
+            sync_frequency_code: None,
+            pages: FnvIndexMap::new(),
+            mfr_status_word: FnvIndexMap::new(),
+            fans_34: FnvIndexMap::new(),
+            vout_modes: FnvIndexMap::new(),
+            in_flight: None,
+            #[cfg(feature = "record")]
+            recording: false,
+            #[cfg(feature = "record")]
+            live_pages: FnvIndexMap::new(),
+            #[cfg(feature = "record")]
+            live_vout_modes: FnvIndexMap::new(),
+            #[cfg(feature = "record")]
+            log: Vec::new(),
+            #[cfg(feature = "metrics")]
+            metrics: Metrics::default(),
+        }
     }
 
     /// Consume self and return the inner `SmbusAdaptor`.
@@ -129,12 +351,634 @@ impl<BUS: I2c + 'static> PmbusAdaptor<BUS> {
         &mut self.smbus
     }
 
+    /// Set the policy applied to latched faults after `read_all_status`.
+    pub fn set_auto_clear(&mut self, policy: AutoClear) {
+        self.auto_clear = policy;
+    }
+
+    /// Return the current auto-clear policy.
+    pub fn auto_clear(&self) -> AutoClear {
+        self.auto_clear
+    }
+
+    /// Select how PEC is applied to transactions.
+    pub fn set_pec_mode(&mut self, mode: PecMode) {
+        self.pec_mode = mode;
+    }
+
+    /// Return the current PEC mode.
+    pub fn pec_mode(&self) -> PecMode {
+        self.pec_mode
+    }
+
+    /// Turn PEC on for every transaction, or off entirely.
+    ///
+    /// Shorthand for [`set_pec_mode`](Self::set_pec_mode) with
+    /// [`PecMode::On`] or [`PecMode::Off`]. When on, every write, read,
+    /// process call and block transfer carries a PEC byte, and a read whose
+    /// PEC doesn't match is handled by the [`PecPolicy`], by default failing
+    /// with [`PmbusError::PecMismatch`]. Check
+    /// [`Capability::pec_supported`] first: a device without PEC NACKs the
+    /// extra byte or ignores the write.
+    pub fn enable_pec(&mut self, on: bool) {
+        self.pec_mode = if on { PecMode::On } else { PecMode::Off };
+    }
+
+    /// Override the hook deciding per command whether PEC is used in
+    /// [`PecMode::Auto`]. Defaults to [`pec::default_pec_required`].
+    ///
+    /// Codes without a `CommandCode` variant (manufacturer-specific commands)
+    /// can't be passed to the hook and always use PEC in this mode.
+    pub fn set_pec_required(&mut self, hook: fn(CommandCode) -> bool) {
+        self.pec_required = hook;
+    }
+
+    /// Select what happens when a read's PEC byte doesn't match.
+    ///
+    /// [`PecPolicy::Retry`] re-issues the whole read transaction, including
+    /// the written part of a process call.
+    pub fn set_pec_policy(&mut self, policy: PecPolicy) {
+        self.pec_policy = policy;
+    }
+
+    /// Return the current PEC mismatch policy.
+    pub fn pec_policy(&self) -> PecPolicy {
+        self.pec_policy
+    }
+
+    /// Number of PEC mismatches seen so far, whatever the policy did with
+    /// them. Wraps on overflow.
+    pub fn pec_mismatch_count(&self) -> u32 {
+        self.pec_mismatches
+    }
+
+    /// Flip the sign of decoded output current.
+    ///
+    /// PMBus reports READ_IOUT as positive while the rail sources current.
+    /// Boards with reversed current-sense wiring read negative; enabling this
+    /// corrects `read_iout_amps`. Raw `read_iout` is never altered.
+    pub fn set_invert_iout(&mut self, invert: bool) {
+        self.invert_iout = invert;
+    }
+
+    /// Return whether decoded output current is sign-inverted.
+    pub fn invert_iout(&self) -> bool {
+        self.invert_iout
+    }
+
+    /// Treat a status register that reads back as all ones (0xFF, or 0xFFFF
+    /// for STATUS_WORD) as [`PmbusError::NoValidResponse`].
+    ///
+    /// A device that is absent or wedged leaves SDA floating high, which
+    /// otherwise decodes as every fault being set. Off by default, since a
+    /// register can legitimately have every bit set.
+    pub fn set_reject_all_ones_status(&mut self, reject: bool) {
+        self.reject_all_ones = reject;
+    }
+
+    /// Return whether all-ones status reads are rejected.
+    pub fn reject_all_ones_status(&self) -> bool {
+        self.reject_all_ones
+    }
+
+    /// Check STATUS_CML after each READ_* telemetry word and retry the read
+    /// up to `retries` times while it flags a communication fault.
+    ///
+    /// Catches reads that completed on the bus but that the device itself
+    /// flagged (PEC_FAILED or COMM_FAULT_OTHER). The flagged bits are
+    /// cleared before each retry; if they are still set after the last one
+    /// the read fails with [`PmbusError::CommFault`]. Skipped for commands
+    /// that are read with PEC, which already covers this. 0 (the default)
+    /// disables the check.
+    pub fn set_cml_retries(&mut self, retries: u8) {
+        self.cml_retries = retries;
+    }
+
+    /// Return the number of telemetry read retries on a STATUS_CML comm fault.
+    pub fn cml_retries(&self) -> u8 {
+        self.cml_retries
+    }
+
+    /// Set the byte order of the 32-bit READ_KWH_IN/OUT counters.
+    ///
+    /// PMBus sends them least significant byte first, but some devices
+    /// send them big-endian.
+    #[cfg(feature = "energy")]
+    pub fn set_kwh_byte_order(&mut self, order: ByteOrder) {
+        self.kwh_byte_order = order;
+    }
+
+    /// Return the byte order used for READ_KWH_IN/OUT.
+    #[cfg(feature = "energy")]
+    pub fn kwh_byte_order(&self) -> ByteOrder {
+        self.kwh_byte_order
+    }
+
+    /// Select the word register [`store_user_all_verified`] reads back to
+    /// confirm a store, or `None` to skip verification.
+    ///
+    /// Pick a register the application has just configured, such as
+    /// VOUT_COMMAND, so a store that didn't land restores a different value.
+    ///
+    /// [`store_user_all_verified`]: Self::store_user_all_verified
+    pub fn set_store_canary(&mut self, canary: Option<CommandCode>) {
+        self.store_canary = canary;
+    }
+
+    /// Return the canary register used to verify stores.
+    pub fn store_canary(&self) -> Option<CommandCode> {
+        self.store_canary
+    }
+
+    /// Select the manufacturer command that reports the sync frequency as
+    /// LINEAR11 kHz, for controllers that report it apart from
+    /// READ_FREQUENCY. `None` (the default) means there is no such command.
+    pub fn set_sync_frequency_code(&mut self, code: Option<u8>) {
+        self.sync_frequency_code = code;
+    }
+
+    /// Return the command read by
+    /// [`read_sync_frequency_khz`](Self::read_sync_frequency_khz).
+    pub fn sync_frequency_code(&self) -> Option<u8> {
+        self.sync_frequency_code
+    }
+
+    /// Enable or disable dry-run mode.
+    ///
+    /// While enabled, every write is appended to the transaction log instead
+    /// of being sent; reads are still issued to the bus. Once the log holds
+    /// [`record::LOG_CAPACITY`] entries further writes fail with
+    /// [`PmbusError::RecordOverflow`].
+    ///
+    /// The page and VOUT_MODE caches follow the recorded writes, so page
+    /// selection in the log stays consistent, and are put back to their
+    /// state before recording once it is disabled, since the devices never
+    /// saw those writes.
+    #[cfg(feature = "record")]
+    pub fn set_recording(&mut self, on: bool) {
+        if on && !self.recording {
+            self.live_pages.clone_from(&self.pages);
+            self.live_vout_modes.clone_from(&self.vout_modes);
+        } else if !on && self.recording {
+            self.pages = core::mem::take(&mut self.live_pages);
+            self.vout_modes = core::mem::take(&mut self.live_vout_modes);
+        }
+        self.recording = on;
+    }
+
+    /// Return whether dry-run mode is enabled.
+    #[cfg(feature = "record")]
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// Take the writes recorded so far, leaving the log empty.
+    #[cfg(feature = "record")]
+    pub fn take_log(&mut self) -> Vec<RecordedWrite, { record::LOG_CAPACITY }> {
+        core::mem::take(&mut self.log)
+    }
+
+    /// Return the bus traffic counted so far.
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> Metrics {
+        self.metrics
+    }
+
+    /// Reset the traffic counters to zero.
+    #[cfg(feature = "metrics")]
+    pub fn reset_metrics(&mut self) {
+        self.metrics = Metrics::default();
+    }
+
     // -----------------------------------------------------------------------
     // Private helpers
     // -----------------------------------------------------------------------
 
-    async fn send_cmd(&mut self, addr: u8, cmd: CommandCode) -> Result<(), BUS::Error> {
-        self.smbus.send_byte(addr, cmd.code()).await
+    fn pec_for(&self, code: u8) -> bool {
+        match self.pec_mode {
+            PecMode::Off => false,
+            PecMode::On => true,
+            PecMode::Auto => CommandCode::from_u8(code).is_none_or(self.pec_required),
+        }
+    }
+
+    /// Re-synchronise the bus after an interrupted transaction to `addr`.
+    ///
+    /// Issues a zero-length write (START, address, STOP), which terminates
+    /// any transfer the device still thinks is in progress, and forgets the
+    /// device's cached PAGE and VOUT_MODE. A NACK here is returned as an
+    /// error but still counts as recovered.
+    pub async fn recover_bus(&mut self, addr: u8) -> Result<(), PmbusError<BUS::Error>> {
+        self.in_flight = None;
+        self.pages.remove(&addr);
+        self.vout_modes.remove(&addr);
+        let result = self.smbus.write(addr, &[]).await;
+        #[cfg(feature = "metrics")]
+        self.metrics.count(&[], result.is_ok());
+        Ok(result?)
+    }
+
+    /// Free a bus whose SDA line is held low by a device stuck mid-byte.
+    ///
+    /// With the I2C pins switched to manual control, SCL is pulsed at
+    /// 100 kHz until SDA is released, up to nine times, and a STOP is then
+    /// generated. The cached PAGE and VOUT_MODE of every device are
+    /// forgotten, since the interrupted transfers may have left them
+    /// anywhere. Returns
+    /// [`PmbusError::BusStuck`] if SDA is still low after the ninth pulse.
+    pub async fn bus_recovery<P: BusRecoveryPins>(
+        &mut self,
+        pins: &mut P,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), PmbusError<P::Error>> {
+        self.in_flight = None;
+        self.pages.clear();
+        self.vout_modes.clear();
+        pins.set_sda(true).map_err(PmbusError::Bus)?;
+        pins.set_scl(true).map_err(PmbusError::Bus)?;
+        let mut clocks = 0;
+        while !pins.sda_is_high().map_err(PmbusError::Bus)? {
+            if clocks == recovery::MAX_CLOCKS {
+                return Err(PmbusError::BusStuck);
+            }
+            pins.set_scl(false).map_err(PmbusError::Bus)?;
+            delay.delay_us(recovery::HALF_PERIOD_US).await;
+            pins.set_scl(true).map_err(PmbusError::Bus)?;
+            delay.delay_us(recovery::HALF_PERIOD_US).await;
+            clocks += 1;
+        }
+        // STOP: SDA rises while SCL is high.
+        pins.set_scl(false).map_err(PmbusError::Bus)?;
+        pins.set_sda(false).map_err(PmbusError::Bus)?;
+        delay.delay_us(recovery::HALF_PERIOD_US).await;
+        pins.set_scl(true).map_err(PmbusError::Bus)?;
+        delay.delay_us(recovery::HALF_PERIOD_US).await;
+        pins.set_sda(true).map_err(PmbusError::Bus)?;
+        Ok(())
+    }
+
+    /// Send `ops` with the PMBus group command protocol, so every device
+    /// acts on its command at the same STOP, e.g. to margin several rails
+    /// together.
+    ///
+    /// Each op is `(address, command, data)`, with `data` exactly as it
+    /// follows the command code on the wire (a block write includes its
+    /// count byte). Frames go out in slice order, but the devices only act
+    /// once the final STOP arrives. PMBus allows each device only one
+    /// command per group, so a repeated address fails with
+    /// [`PmbusError::DuplicateGroupAddress`]; more than
+    /// [`MAX_GROUP_OPS`](group::MAX_GROUP_OPS) ops fail with
+    /// [`PmbusError::GroupTooLarge`], and over 33 data bytes with
+    /// [`PmbusError::BlockTooLong`]. Nothing is sent on any of these.
+    ///
+    /// The PEC mode decides per command whether a PEC byte is appended to
+    /// that device's frame. The group can't change PAGE, so paged commands
+    /// go to each device's current page. In dry-run mode the writes are
+    /// logged instead of sent.
+    pub async fn group_command<G: GroupCommandBus>(
+        &mut self,
+        bus: &mut G,
+        ops: &[(u8, CommandCode, &[u8])],
+    ) -> Result<(), PmbusError<G::Error>> {
+        if ops.len() > group::MAX_GROUP_OPS {
+            return Err(PmbusError::GroupTooLarge);
+        }
+        let mut frames: Vec<Vec<u8, { group::MAX_FRAME_LEN }>, { group::MAX_GROUP_OPS }> =
+            Vec::new();
+        for (i, &(addr, cmd, data)) in ops.iter().enumerate() {
+            if ops[..i].iter().any(|&(other, ..)| other == addr) {
+                return Err(PmbusError::DuplicateGroupAddress { addr });
+            }
+            if data.len() > group::MAX_FRAME_LEN - 2 {
+                return Err(PmbusError::BlockTooLong);
+            }
+            let mut frame = Vec::new();
+            let _ = frame.push(cmd.code());
+            let _ = frame.extend_from_slice(data);
+            if self.pec_for(cmd.code()) {
+                let _ = frame.push(Pec::new().addr_write(addr).bytes(&frame).finish());
+            }
+            let _ = frames.push(frame);
+        }
+        if ops.is_empty() {
+            return Ok(());
+        }
+        #[cfg(feature = "record")]
+        if self.recording {
+            for &(addr, cmd, data) in ops {
+                let tx = RecordedWrite::new(addr, cmd.code(), &[data])
+                    .ok_or(PmbusError::RecordOverflow)?;
+                self.log.push(tx).map_err(|_| PmbusError::RecordOverflow)?;
+            }
+            return Ok(());
+        }
+        let refs: Vec<(u8, &[u8]), { group::MAX_GROUP_OPS }> = ops
+            .iter()
+            .zip(&frames)
+            .map(|(&(addr, ..), frame)| (addr, frame.as_slice()))
+            .collect();
+        bus.write_group(&refs).await.map_err(PmbusError::Bus)
+    }
+
+    /// Run one bus transaction, recovering first if a previous one was dropped.
+    async fn bus_transaction(
+        &mut self,
+        addr: u8,
+        ops: &mut [Operation<'_>],
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        if let Some(interrupted) = self.in_flight {
+            self.recover_bus(interrupted).await?;
+        }
+        self.in_flight = Some(addr);
+        let result = self.smbus.transaction(addr, ops).await;
+        self.in_flight = None;
+        #[cfg(feature = "metrics")]
+        self.metrics.count(ops, result.is_ok());
+        Ok(result?)
+    }
+
+    /// Append a write to the dry-run log.
+    #[cfg(feature = "record")]
+    fn record(
+        &mut self,
+        addr: u8,
+        command: u8,
+        parts: &[&[u8]],
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let tx = RecordedWrite::new(addr, command, parts).ok_or(PmbusError::RecordOverflow)?;
+        self.log.push(tx).map_err(|_| PmbusError::RecordOverflow)
+    }
+
+    /// Write `bytes` in one frame, appending the PEC byte if `pec` is set.
+    async fn write_frame(
+        &mut self,
+        addr: u8,
+        bytes: &[u8],
+        pec: bool,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        #[cfg(feature = "record")]
+        if self.recording {
+            return self.record(addr, bytes[0], &[&bytes[1..]]);
+        }
+        if pec {
+            let crc = [Pec::new().addr_write(addr).bytes(bytes).finish()];
+            let mut ops = [Operation::Write(bytes), Operation::Write(&crc)];
+            self.bus_transaction(addr, &mut ops).await?;
+        } else {
+            self.bus_transaction(addr, &mut [Operation::Write(bytes)])
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Write `cmd`, repeated start, then fill `buf`, checking PEC if `pec` is set.
+    async fn read_frame(
+        &mut self,
+        addr: u8,
+        cmd: &[u8],
+        buf: &mut [u8],
+        pec: bool,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        if !pec {
+            let mut ops = [Operation::Write(cmd), Operation::Read(buf)];
+            self.bus_transaction(addr, &mut ops).await?;
+            return Ok(());
+        }
+        let mut retries = self.pec_retries();
+        loop {
+            let mut received = [0u8];
+            let mut ops = [
+                Operation::Write(cmd),
+                Operation::Read(buf),
+                Operation::Read(&mut received),
+            ];
+            self.bus_transaction(addr, &mut ops).await?;
+            let expected = Pec::new()
+                .addr_write(addr)
+                .bytes(cmd)
+                .addr_read(addr)
+                .bytes(buf)
+                .finish();
+            let received = received[0];
+            if expected == received || !self.on_pec_mismatch(&mut retries, expected, received)? {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Retries allowed by the PEC policy for one read.
+    fn pec_retries(&self) -> u8 {
+        match self.pec_policy {
+            PecPolicy::Retry(n) => n,
+            _ => 0,
+        }
+    }
+
+    /// Apply the PEC policy to a mismatch: `Ok(true)` to retry the read,
+    /// `Ok(false)` to accept the data.
+    fn on_pec_mismatch(
+        &mut self,
+        retries: &mut u8,
+        expected: u8,
+        received: u8,
+    ) -> Result<bool, PmbusError<BUS::Error>> {
+        self.pec_mismatches = self.pec_mismatches.wrapping_add(1);
+        match self.pec_policy {
+            PecPolicy::WarnAndAccept => Ok(false),
+            PecPolicy::Retry(_) if *retries > 0 => {
+                *retries -= 1;
+                Ok(true)
+            }
+            _ => Err(PmbusError::PecMismatch { expected, received }),
+        }
+    }
+
+    /// SMBus block write: `[code, count, data..]` plus optional PEC.
+    ///
+    /// The count byte is always `data.len()`, so callers pass the payload
+    /// only. The count is a single byte, so payloads over 255 bytes are
+    /// rejected with [`PmbusError::BlockTooLong`] rather than truncated.
+    async fn block_write_frame(
+        &mut self,
+        addr: u8,
+        code: u8,
+        data: &[u8],
+        pec: bool,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let header = [code, block_count(data)?];
+        #[cfg(feature = "record")]
+        if self.recording {
+            return self.record(addr, code, &[&header[1..], data]);
+        }
+        if pec {
+            let crc = [Pec::new()
+                .addr_write(addr)
+                .bytes(&header)
+                .bytes(data)
+                .finish()];
+            let mut ops = [
+                Operation::Write(&header),
+                Operation::Write(data),
+                Operation::Write(&crc),
+            ];
+            self.bus_transaction(addr, &mut ops).await?;
+        } else {
+            let mut ops = [Operation::Write(&header), Operation::Write(data)];
+            self.bus_transaction(addr, &mut ops).await?;
+        }
+        Ok(())
+    }
+
+    /// Write `header` (and `data`, if any), repeated start, then read an
+    /// SMBus block. The returned vector starts with the count byte.
+    async fn block_read_frame(
+        &mut self,
+        addr: u8,
+        header: &[u8],
+        data: &[u8],
+        pec: bool,
+    ) -> Result<Vec<u8, 33>, PmbusError<BUS::Error>> {
+        // Count byte, up to 32 data bytes and the PEC byte.
+        let mut buf = [0u8; 34];
+        let n = if pec { 34 } else { 33 };
+        let mut retries = self.pec_retries();
+        let count = loop {
+            if data.is_empty() {
+                let mut ops = [Operation::Write(header), Operation::Read(&mut buf[..n])];
+                self.bus_transaction(addr, &mut ops).await?;
+            } else {
+                let mut ops = [
+                    Operation::Write(header),
+                    Operation::Write(data),
+                    Operation::Read(&mut buf[..n]),
+                ];
+                self.bus_transaction(addr, &mut ops).await?;
+            }
+            let count = (buf[0] as usize).min(32);
+            if !pec {
+                break count;
+            }
+            let expected = Pec::new()
+                .addr_write(addr)
+                .bytes(header)
+                .bytes(data)
+                .addr_read(addr)
+                .bytes(&buf[..=count])
+                .finish();
+            let received = buf[count + 1];
+            if expected == received || !self.on_pec_mismatch(&mut retries, expected, received)? {
+                break count;
+            }
+        };
+        // A count above 32 claims more than a block can carry, so only the
+        // first 32 data bytes were read.
+        if buf[0] > 32 {
+            return Err(PmbusError::ShortRead {
+                expected: buf[0] as usize,
+                got: 32,
+            });
+        }
+        Vec::from_slice(&buf[..=count]).map_err(|_| PmbusError::InvalidResponseLength)
+    }
+
+    /// Dispatch one SMBus transaction.
+    ///
+    /// This is the single path to the bus: PEC, dry-run recording and
+    /// recovery after a dropped transaction are all applied here. A
+    /// `WriteRead` with an empty `write` fails with
+    /// [`PmbusError::EncodingError`] before anything is sent.
+    pub async fn execute(
+        &mut self,
+        tx: Transaction<'_>,
+    ) -> Result<Response, PmbusError<BUS::Error>> {
+        let pec = self.pec_for(tx.code());
+        match tx {
+            Transaction::SendByte { addr, code } => {
+                self.write_frame(addr, &[code], pec).await?;
+                Ok(Response::Done)
+            }
+            Transaction::WriteByte { addr, code, data } => {
+                self.write_frame(addr, &[code, data], pec).await?;
+                Ok(Response::Done)
+            }
+            Transaction::ReadByte { addr, code } => {
+                let mut buf = [0u8; 1];
+                self.read_frame(addr, &[code], &mut buf, pec).await?;
+                Ok(Response::Byte(buf[0]))
+            }
+            Transaction::WriteWord { addr, code, data } => {
+                let bytes = data.to_le_bytes();
+                self.write_frame(addr, &[code, bytes[0], bytes[1]], pec)
+                    .await?;
+                Ok(Response::Done)
+            }
+            Transaction::ReadWord { addr, code } => {
+                let mut buf = [0u8; 2];
+                self.read_frame(addr, &[code], &mut buf, pec).await?;
+                Ok(Response::Word(u16::from_le_bytes(buf)))
+            }
+            Transaction::ProcessCall { addr, code, data } => {
+                let bytes = data.to_le_bytes();
+                let mut buf = [0u8; 2];
+                self.read_frame(addr, &[code, bytes[0], bytes[1]], &mut buf, pec)
+                    .await?;
+                Ok(Response::Word(u16::from_le_bytes(buf)))
+            }
+            Transaction::BlockWrite { addr, code, data } => {
+                self.block_write_frame(addr, code, data, pec).await?;
+                Ok(Response::Done)
+            }
+            Transaction::BlockRead { addr, code } => {
+                let block = self.block_read_frame(addr, &[code], &[], pec).await?;
+                Ok(Response::Block(block))
+            }
+            Transaction::BlockProcessCall { addr, code, data } => {
+                let block = self
+                    .block_read_frame(addr, &[code, block_count(data)?], data, pec)
+                    .await?;
+                Ok(Response::Block(block))
+            }
+            // Every frame starts with a command code.
+            Transaction::WriteRead { write: [], .. } => Err(PmbusError::EncodingError),
+            Transaction::WriteRead { addr, write, read } => {
+                if read.is_empty() {
+                    self.write_frame(addr, write, pec).await?;
+                } else {
+                    self.read_frame(addr, write, read, pec).await?;
+                }
+                Ok(Response::Done)
+            }
+        }
+    }
+
+    async fn execute_byte(&mut self, tx: Transaction<'_>) -> Result<u8, PmbusError<BUS::Error>> {
+        self.execute(tx)
+            .await?
+            .byte()
+            .ok_or(PmbusError::InvalidResponseLength)
+    }
+
+    async fn execute_word(&mut self, tx: Transaction<'_>) -> Result<u16, PmbusError<BUS::Error>> {
+        self.execute(tx)
+            .await?
+            .word()
+            .ok_or(PmbusError::InvalidResponseLength)
+    }
+
+    async fn execute_block(
+        &mut self,
+        tx: Transaction<'_>,
+    ) -> Result<Vec<u8, 33>, PmbusError<BUS::Error>> {
+        self.execute(tx)
+            .await?
+            .block()
+            .ok_or(PmbusError::InvalidResponseLength)
+    }
+
+    async fn send_cmd(&mut self, addr: u8, cmd: CommandCode) -> Result<(), PmbusError<BUS::Error>> {
+        let code = cmd.code();
+        self.execute(Transaction::SendByte { addr, code }).await?;
+        Ok(())
     }
 
     async fn write_cmd_byte(
@@ -142,12 +986,21 @@ impl<BUS: I2c + 'static> PmbusAdaptor<BUS> {
         addr: u8,
         cmd: CommandCode,
         data: u8,
-    ) -> Result<(), BUS::Error> {
-        self.smbus.write_byte(addr, cmd.code(), data).await
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let code = cmd.code();
+        self.execute(Transaction::WriteByte { addr, code, data })
+            .await?;
+        Ok(())
     }
 
-    async fn read_cmd_byte(&mut self, addr: u8, cmd: CommandCode) -> Result<u8, BUS::Error> {
-        self.smbus.read_byte(addr, cmd.code()).await
+    async fn read_cmd_byte(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+    ) -> Result<u8, PmbusError<BUS::Error>> {
+        let code = cmd.code();
+        self.execute_byte(Transaction::ReadByte { addr, code })
+            .await
     }
 
     async fn write_cmd_word(
@@ -155,12 +1008,112 @@ impl<BUS: I2c + 'static> PmbusAdaptor<BUS> {
         addr: u8,
         cmd: CommandCode,
         data: u16,
-    ) -> Result<(), BUS::Error> {
-        self.smbus.write_word(addr, cmd.code(), data).await
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let code = cmd.code();
+        self.execute(Transaction::WriteWord { addr, code, data })
+            .await?;
+        Ok(())
+    }
+
+    async fn read_cmd_word(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+    ) -> Result<u16, PmbusError<BUS::Error>> {
+        let code = cmd.code();
+        self.execute_word(Transaction::ReadWord { addr, code })
+            .await
+    }
+
+    /// Read a READ_* telemetry word, applying the
+    /// [STATUS_CML retry](Self::set_cml_retries) policy.
+    async fn read_telemetry_word(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+    ) -> Result<u16, PmbusError<BUS::Error>> {
+        if self.cml_retries == 0 || self.pec_for(cmd.code()) {
+            return self.read_cmd_word(addr, cmd).await;
+        }
+        let comm = StatusCml::PEC_FAILED | StatusCml::COMM_FAULT_OTHER;
+        let mut retries = self.cml_retries;
+        loop {
+            let raw = self.read_cmd_word(addr, cmd).await?;
+            let faults = self.get_status_cml(addr).await?.intersection(comm);
+            if faults.is_empty() {
+                return Ok(raw);
+            }
+            self.set_status_cml(addr, faults).await?;
+            if retries == 0 {
+                return Err(PmbusError::CommFault);
+            }
+            retries -= 1;
+        }
+    }
+
+    /// Read a status byte, applying the
+    /// [all-ones](Self::set_reject_all_ones_status) check.
+    async fn read_status_byte(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+    ) -> Result<u8, PmbusError<BUS::Error>> {
+        let raw = self.read_cmd_byte(addr, cmd).await?;
+        if self.reject_all_ones && raw == 0xFF {
+            return Err(PmbusError::NoValidResponse);
+        }
+        Ok(raw)
+    }
+
+    /// Word counterpart of [`read_status_byte`](Self::read_status_byte).
+    async fn read_status_word(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+    ) -> Result<u16, PmbusError<BUS::Error>> {
+        let raw = self.read_cmd_word(addr, cmd).await?;
+        if self.reject_all_ones && raw == 0xFFFF {
+            return Err(PmbusError::NoValidResponse);
+        }
+        Ok(raw)
+    }
+
+    /// Fold the values of `commands` on `page` into a CRC-32.
+    ///
+    /// Each command's code and value bytes are hashed in order (blocks
+    /// including their count byte), so identically configured devices give
+    /// the same fingerprint and a production run can be checked against a
+    /// golden value. Returns [`PmbusError::NotReadable`] for commands without
+    /// a plain byte, word or block read (see [`CommandCode::data_width`]).
+    pub async fn config_fingerprint(
+        &mut self,
+        addr: u8,
+        page: u8,
+        commands: &[CommandCode],
+    ) -> Result<u32, PmbusError<BUS::Error>> {
+        self.select_page(addr, page).await?;
+        let mut crc = crc32::Crc32::new();
+        for &cmd in commands {
+            crc = crc.bytes(&[cmd.code()]);
+            crc = match cmd.data_width() {
+                DataWidth::Byte => crc.bytes(&[self.read_cmd_byte(addr, cmd).await?]),
+                DataWidth::Word => crc.bytes(&self.read_cmd_word(addr, cmd).await?.to_le_bytes()),
+                DataWidth::Block => crc.bytes(&self.block_read_cmd(addr, cmd).await?),
+                DataWidth::None | DataWidth::Other => return Err(PmbusError::NotReadable),
+            };
+        }
+        Ok(crc.finish())
     }
 
-    async fn read_cmd_word(&mut self, addr: u8, cmd: CommandCode) -> Result<u16, BUS::Error> {
-        self.smbus.read_word(addr, cmd.code()).await
+    async fn process_call_cmd(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+        data: u16,
+    ) -> Result<u16, PmbusError<BUS::Error>> {
+        let code = cmd.code();
+        self.execute_word(Transaction::ProcessCall { addr, code, data })
+            .await
     }
 
     async fn block_write_cmd(
@@ -168,16 +1121,21 @@ impl<BUS: I2c + 'static> PmbusAdaptor<BUS> {
         addr: u8,
         cmd: CommandCode,
         data: &[u8],
-    ) -> Result<(), BUS::Error> {
-        self.smbus.block_write(addr, cmd.code(), data).await
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let code = cmd.code();
+        self.execute(Transaction::BlockWrite { addr, code, data })
+            .await?;
+        Ok(())
     }
 
     async fn block_read_cmd(
         &mut self,
         addr: u8,
         cmd: CommandCode,
-    ) -> Result<Vec<u8, 32>, BUS::Error> {
-        self.smbus.block_read(addr, cmd.code()).await
+    ) -> Result<Vec<u8, 33>, PmbusError<BUS::Error>> {
+        let code = cmd.code();
+        self.execute_block(Transaction::BlockRead { addr, code })
+            .await
     }
 
     async fn block_process_call_cmd(
@@ -185,91 +1143,444 @@ impl<BUS: I2c + 'static> PmbusAdaptor<BUS> {
         addr: u8,
         cmd: CommandCode,
         data: &[u8],
-    ) -> Result<Vec<u8, 32>, BUS::Error> {
-        self.smbus
-            .block_read_process_call(addr, cmd.code(), data)
+    ) -> Result<Vec<u8, 33>, PmbusError<BUS::Error>> {
+        let code = cmd.code();
+        self.execute_block(Transaction::BlockProcessCall { addr, code, data })
             .await
     }
 
+    /// Write `write`, then fill `read` (if non-empty) after a repeated start.
+    async fn write_read_cmd(
+        &mut self,
+        addr: u8,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.execute(Transaction::WriteRead { addr, write, read })
+            .await?;
+        Ok(())
+    }
+
     // =======================================================================
     // Send-byte commands (no data)
     // =======================================================================
 
     pmbus_send_byte!(clear_faults, ClearFaults);
     pmbus_send_byte!(store_default_all, StoreDefaultAll);
-    pmbus_send_byte!(restore_default_all, RestoreDefaultAll);
     pmbus_send_byte!(store_user_all, StoreUserAll);
-    pmbus_send_byte!(restore_user_all, RestoreUserAll);
 
-    // =======================================================================
-    // Byte read/write commands
-    // =======================================================================
+    /// Issue RESTORE_DEFAULT_ALL (0x12).
+    ///
+    /// The reload resets PAGE and VOUT_MODE along with everything else, so
+    /// the cached page and VOUT_MODE of `addr` are forgotten on success.
+    pub async fn restore_default_all(&mut self, addr: u8) -> Result<(), PmbusError<BUS::Error>> {
+        self.send_cmd(addr, CommandCode::RestoreDefaultAll).await?;
+        self.invalidate_page_cache(addr);
+        Ok(())
+    }
 
-    pmbus_byte_rw!(set_page, get_page, Page);
-    pmbus_byte_rw!(set_operation, get_operation, Operation);
-    pmbus_byte_rw!(set_on_off_config, get_on_off_config, OnOffConfig);
-    pmbus_byte_rw!(set_phase, get_phase, Phase);
-    pmbus_byte_rw!(set_write_protect, get_write_protect, WriteProtect);
-    pmbus_byte_rw!(set_power_mode, get_power_mode, PowerMode);
-    pmbus_byte_rw!(set_fan_config_12, get_fan_config_12, FanConfig12);
-    pmbus_byte_rw!(set_fan_config_34, get_fan_config_34, FanConfig34);
+    /// Issue RESTORE_USER_ALL (0x16).
+    ///
+    /// As with [`restore_default_all`](Self::restore_default_all), the cached
+    /// page and VOUT_MODE of `addr` are forgotten on success.
+    pub async fn restore_user_all(&mut self, addr: u8) -> Result<(), PmbusError<BUS::Error>> {
+        self.send_cmd(addr, CommandCode::RestoreUserAll).await?;
+        self.invalidate_page_cache(addr);
+        Ok(())
+    }
 
-    // Fault responses (byte r/w)
-    pmbus_byte_rw!(
-        set_vout_ov_fault_response,
-        get_vout_ov_fault_response,
-        VoutOvFaultResponse
-    );
-    pmbus_byte_rw!(
-        set_vout_uv_fault_response,
-        get_vout_uv_fault_response,
-        VoutUvFaultResponse
-    );
-    pmbus_byte_rw!(
+    /// Issue STORE_USER_ALL (0x15) and check that it took effect.
+    ///
+    /// With a [canary](Self::set_store_canary) configured, the canary is read
+    /// before the store, RESTORE_USER_ALL (0x16) reloads the stored values,
+    /// and the canary is read again. A different value means the NVM write
+    /// failed (e.g. write-protected device) and returns
+    /// [`PmbusError::StoreFailed`]. Without a canary this is a plain store.
+    ///
+    /// The NVM write keeps the device busy, so BUSY is waited out as in
+    /// [`wait_if_busy`](Self::wait_if_busy) after the store and again after
+    /// the restore. A NACKed RESTORE_USER_ALL is retried every millisecond
+    /// within the same `timeout_ms`.
+    pub async fn store_user_all_verified(
+        &mut self,
+        addr: u8,
+        timeout_ms: u32,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let Some(canary) = self.store_canary else {
+            return self.store_user_all(addr).await;
+        };
+        let before = self.read_cmd_word(addr, canary).await?;
+        self.store_user_all(addr).await?;
+        self.wait_if_busy(addr, timeout_ms, delay).await?;
+        let mut elapsed_ms = 0;
+        loop {
+            match self.restore_user_all(addr).await {
+                Ok(()) => break,
+                Err(PmbusError::Bus(e)) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => {}
+                Err(e) => return Err(e),
+            }
+            if elapsed_ms >= timeout_ms {
+                return Err(PmbusError::DeviceBusy);
+            }
+            delay.delay_ms(1).await;
+            elapsed_ms += 1;
+        }
+        self.wait_if_busy(addr, timeout_ms, delay).await?;
+        if self.read_cmd_word(addr, canary).await? != before {
+            return Err(PmbusError::StoreFailed);
+        }
+        Ok(())
+    }
+
+    /// Poll STATUS_BYTE until the device clears BUSY.
+    ///
+    /// Meant for after commands that keep a device occupied, such as
+    /// STORE_USER_ALL or RESTORE_USER_ALL. A NACK while polling is taken as
+    /// the device still being busy. Polls every millisecond and returns
+    /// [`PmbusError::DeviceBusy`] once `timeout_ms` has elapsed.
+    pub async fn wait_if_busy(
+        &mut self,
+        addr: u8,
+        timeout_ms: u32,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let mut elapsed_ms = 0;
+        loop {
+            match self.get_status_byte(addr).await {
+                Ok(status) if !status.contains(StatusByte::BUSY) => return Ok(()),
+                Ok(_) => {}
+                Err(PmbusError::Bus(e)) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => {}
+                Err(e) => return Err(e),
+            }
+            if elapsed_ms >= timeout_ms {
+                return Err(PmbusError::DeviceBusy);
+            }
+            delay.delay_ms(1).await;
+            elapsed_ms += 1;
+        }
+    }
+
+    /// Issue RESTORE_DEFAULT_ALL (0x12) and wait for the device to finish.
+    ///
+    /// Waits up to `timeout_ms` for BUSY to clear, as in
+    /// [`wait_if_busy`](Self::wait_if_busy). With `verify` set to
+    /// `(cmd, expected)`, the word at `cmd` is then read back and
+    /// [`PmbusError::ResetFailed`] is returned if it isn't `expected`.
+    pub async fn factory_reset(
+        &mut self,
+        addr: u8,
+        verify: Option<(CommandCode, u16)>,
+        timeout_ms: u32,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.restore_default_all(addr).await?;
+        self.wait_if_busy(addr, timeout_ms, delay).await?;
+        if let Some((cmd, expected)) = verify {
+            if self.read_cmd_word(addr, cmd).await? != expected {
+                return Err(PmbusError::ResetFailed);
+            }
+        }
+        Ok(())
+    }
+
+    // =======================================================================
+    // PAGE — with per-address cache
+    // =======================================================================
+
+    /// Write PAGE (0x00) and remember it as the device's active page.
+    pub async fn set_page(&mut self, addr: u8, page: u8) -> Result<(), PmbusError<BUS::Error>> {
+        let result = self.write_cmd_byte(addr, CommandCode::Page, page).await;
+        match result {
+            Ok(()) => self.cache_page(addr, page),
+            Err(_) => self.invalidate_page_cache(addr),
+        }
+        result
+    }
+
+    /// Read PAGE (0x00). The cached page is not consulted or updated.
+    pub async fn get_page(&mut self, addr: u8) -> Result<u8, PmbusError<BUS::Error>> {
+        self.read_cmd_byte(addr, CommandCode::Page).await
+    }
+
+    /// Read the device's active PAGE and reconcile the cache with it.
+    ///
+    /// Useful at start-up, since some devices power up on a non-zero page.
+    pub async fn current_page(&mut self, addr: u8) -> Result<u8, PmbusError<BUS::Error>> {
+        let page = self.get_page(addr).await?;
+        self.cache_page(addr, page);
+        Ok(page)
+    }
+
+    /// Select `page`, skipping the PAGE write if the cache says it's active.
+    pub async fn select_page(&mut self, addr: u8, page: u8) -> Result<(), PmbusError<BUS::Error>> {
+        if self.cached_page(addr) == Some(page) {
+            return Ok(());
+        }
+        self.set_page(addr, page).await
+    }
+
+    /// Find the pages `addr` accepts by writing each of `0..max` to PAGE and
+    /// reading it back.
+    ///
+    /// A page counts as supported if the readback matches; a NACKed write or
+    /// read skips it. At most 32 pages are probed. The original page is
+    /// restored afterwards, also when another error stops the probe. Rejected writes usually set STATUS_CML's invalid
+    /// data bit, so clear faults after probing if that matters.
+    pub async fn enumerate_pages(
+        &mut self,
+        addr: u8,
+        max: u8,
+    ) -> Result<Vec<u8, 32>, PmbusError<BUS::Error>> {
+        let original = self.get_page(addr).await?;
+        let mut pages = Vec::new();
+        for page in 0..max.min(32) {
+            let readback = match self.set_page(addr, page).await {
+                Ok(()) => self.get_page(addr).await,
+                Err(e) => Err(e),
+            };
+            match readback {
+                Ok(read) if read == page => {
+                    let _ = pages.push(page);
+                }
+                Ok(_) => {}
+                Err(PmbusError::Bus(e)) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => {}
+                Err(e) => {
+                    let _ = self.set_page(addr, original).await;
+                    return Err(e);
+                }
+            }
+        }
+        self.set_page(addr, original).await?;
+        Ok(pages)
+    }
+
+    /// Select each of `pages` and issue CLEAR_FAULTS (0x03) on it.
+    ///
+    /// Every page is attempted even if an earlier one fails, and the result
+    /// of each is returned alongside its page. At most 32 pages are cleared.
+    /// On devices that support it, [`clear_faults_page_all`] does the
+    /// same with a single CLEAR_FAULTS.
+    ///
+    /// [`clear_faults_page_all`]: Self::clear_faults_page_all
+    pub async fn clear_faults_all_pages(
+        &mut self,
+        addr: u8,
+        pages: &[u8],
+    ) -> Vec<(u8, Result<(), PmbusError<BUS::Error>>), 32> {
+        let mut results = Vec::new();
+        for &page in pages.iter().take(32) {
+            let result = match self.select_page(addr, page).await {
+                Ok(()) => self.clear_faults(addr).await,
+                Err(e) => Err(e),
+            };
+            let _ = results.push((page, result));
+        }
+        results
+    }
+
+    /// Issue CLEAR_FAULTS (0x03) with PAGE set to [`PAGE_ALL`], clearing
+    /// every page of a device that supports the all-pages setting.
+    ///
+    /// The previously active page, taken from the cache or read back with
+    /// [`get_page`](Self::get_page) if unknown, is selected again afterwards,
+    /// also when CLEAR_FAULTS fails. A CLEAR_FAULTS error takes precedence
+    /// over one from restoring the page.
+    pub async fn clear_faults_page_all(&mut self, addr: u8) -> Result<(), PmbusError<BUS::Error>> {
+        let previous = match self.cached_page(addr) {
+            Some(page) => page,
+            None => self.get_page(addr).await?,
+        };
+        self.select_page(addr, PAGE_ALL).await?;
+        let result = self.clear_faults(addr).await;
+        let restored = self.select_page(addr, previous).await;
+        result.and(restored)
+    }
+
+    /// The page last written to or read from `addr`, if known.
+    pub fn cached_page(&self, addr: u8) -> Option<u8> {
+        self.pages.get(&addr).copied()
+    }
+
+    /// Forget the cached page of `addr`, e.g. after another master changed it.
+    ///
+    /// The cached VOUT_MODE goes with it, since it belongs to that page.
+    pub fn invalidate_page_cache(&mut self, addr: u8) {
+        self.pages.remove(&addr);
+        self.vout_modes.remove(&addr);
+    }
+
+    fn cache_page(&mut self, addr: u8, page: u8) {
+        // VOUT_MODE can differ per page, so a page change drops it.
+        if self.cached_page(addr) != Some(page) {
+            self.vout_modes.remove(&addr);
+        }
+        let _ = self.pages.insert(addr, page);
+    }
+
+    // =======================================================================
+    // Read-modify-write
+    // =======================================================================
+
+    /// Read a byte register, pass it through `f`, and write the result back.
+    ///
+    /// For changing one field of a config byte (ON_OFF_CONFIG, fault
+    /// responses, FAN_CONFIG_xx) without disturbing the others. Returns the
+    /// written value. The write is skipped when `f` leaves the value
+    /// unchanged. Nothing locks the register between the read and the write,
+    /// so another bus master can still race it.
+    pub async fn modify_byte(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+        f: impl FnOnce(u8) -> u8,
+    ) -> Result<u8, PmbusError<BUS::Error>> {
+        let old = self.read_cmd_byte(addr, cmd).await?;
+        let new = f(old);
+        if new != old {
+            self.write_cmd_byte(addr, cmd, new).await?;
+        }
+        Ok(new)
+    }
+
+    /// Word-register counterpart of [`modify_byte`](Self::modify_byte).
+    pub async fn modify_word(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+        f: impl FnOnce(u16) -> u16,
+    ) -> Result<u16, PmbusError<BUS::Error>> {
+        let old = self.read_cmd_word(addr, cmd).await?;
+        let new = f(old);
+        if new != old {
+            self.write_cmd_word(addr, cmd, new).await?;
+        }
+        Ok(new)
+    }
+
+    // =======================================================================
+    // Byte read/write commands
+    // =======================================================================
+
+    pmbus_byte_rw!(set_operation, get_operation, Operation);
+    pmbus_byte_rw!(set_on_off_config, get_on_off_config, OnOffConfig);
+    pmbus_byte_rw!(set_phase, get_phase, Phase);
+    pmbus_byte_rw!(set_write_protect, get_write_protect, WriteProtect);
+    pmbus_byte_rw!(set_power_mode, get_power_mode, PowerMode);
+    #[cfg(feature = "fans")]
+    pmbus_byte_rw!(set_fan_config_12, get_fan_config_12, FanConfig12);
+    #[cfg(feature = "fans")]
+    pmbus_byte_rw!(set_fan_config_34, get_fan_config_34, FanConfig34);
+
+    /// Set the margin state in OPERATION (0x01).
+    ///
+    /// Rewrites bits \[5:2\] only, so the on/off bits are preserved, and
+    /// returns the OPERATION byte written. `fault_action` decides whether
+    /// protection stays active while margined.
+    pub async fn set_margin(
+        &mut self,
+        addr: u8,
+        direction: MarginDir,
+        fault_action: MarginFaultAction,
+    ) -> Result<u8, PmbusError<BUS::Error>> {
+        let bits = operation::margin_bits(direction, fault_action);
+        self.modify_byte(addr, CommandCode::Operation, |op| {
+            (op & !operation::MARGIN_MASK) | bits
+        })
+        .await
+    }
+
+    /// Turn the output on through OPERATION (0x01).
+    ///
+    /// First sets ON_OFF_CONFIG (0x02) bits 4 and 3 so the device obeys
+    /// OPERATION, preserving the control-pin bits, and reads it back; a
+    /// device that keeps its own setting fails with
+    /// [`PmbusError::OnOffConfigRejected`] before OPERATION is touched.
+    /// Then sets OPERATION bit 7, leaving the margin bits as they are.
+    pub async fn enable_via_operation(&mut self, addr: u8) -> Result<(), PmbusError<BUS::Error>> {
+        self.modify_byte(addr, CommandCode::OnOffConfig, |cfg| {
+            cfg | operation::ON_OFF_USE_OPERATION
+        })
+        .await?;
+        let cfg = self.read_cmd_byte(addr, CommandCode::OnOffConfig).await?;
+        if cfg & operation::ON_OFF_USE_OPERATION != operation::ON_OFF_USE_OPERATION {
+            return Err(PmbusError::OnOffConfigRejected);
+        }
+        self.modify_byte(addr, CommandCode::Operation, |op| {
+            op | operation::OPERATION_ON
+        })
+        .await?;
+        Ok(())
+    }
+
+    // Fault responses (byte r/w)
+    #[cfg(feature = "limits")]
+    pmbus_byte_rw!(
+        set_vout_ov_fault_response,
+        get_vout_ov_fault_response,
+        VoutOvFaultResponse
+    );
+    #[cfg(feature = "limits")]
+    pmbus_byte_rw!(
+        set_vout_uv_fault_response,
+        get_vout_uv_fault_response,
+        VoutUvFaultResponse
+    );
+    #[cfg(feature = "limits")]
+    pmbus_byte_rw!(
         set_iout_oc_fault_response,
         get_iout_oc_fault_response,
         IoutOcFaultResponse
     );
+    #[cfg(feature = "limits")]
     pmbus_byte_rw!(
         set_iout_oc_lv_fault_response,
         get_iout_oc_lv_fault_response,
         IoutOcLvFaultResponse
     );
+    #[cfg(feature = "limits")]
     pmbus_byte_rw!(
         set_iout_uc_fault_response,
         get_iout_uc_fault_response,
         IoutUcFaultResponse
     );
+    #[cfg(feature = "limits")]
     pmbus_byte_rw!(
         set_ot_fault_response,
         get_ot_fault_response,
         OtFaultResponse
     );
+    #[cfg(feature = "limits")]
     pmbus_byte_rw!(
         set_ut_fault_response,
         get_ut_fault_response,
         UtFaultResponse
     );
+    #[cfg(feature = "limits")]
     pmbus_byte_rw!(
         set_vin_ov_fault_response,
         get_vin_ov_fault_response,
         VinOvFaultResponse
     );
+    #[cfg(feature = "limits")]
     pmbus_byte_rw!(
         set_vin_uv_fault_response,
         get_vin_uv_fault_response,
         VinUvFaultResponse
     );
+    #[cfg(feature = "limits")]
     pmbus_byte_rw!(
         set_iin_oc_fault_response,
         get_iin_oc_fault_response,
         IinOcFaultResponse
     );
+    #[cfg(feature = "limits")]
     pmbus_byte_rw!(
         set_ton_max_fault_response,
         get_ton_max_fault_response,
         TonMaxFaultResponse
     );
+    #[cfg(feature = "limits")]
     pmbus_byte_rw!(
         set_pout_op_fault_response,
         get_pout_op_fault_response,
@@ -287,17 +1598,71 @@ impl<BUS: I2c + 'static> PmbusAdaptor<BUS> {
     pmbus_read_byte_only!(get_pmbus_revision, PmbusRevision);
     pmbus_read_byte_only!(get_mfr_pin_accuracy, MfrPinAccuracy);
 
+    /// Read CAPABILITY (0x19) and decode it, e.g. to decide whether to
+    /// enable PEC before talking to the device.
+    pub async fn get_capability_parsed(
+        &mut self,
+        addr: u8,
+    ) -> Result<Capability, PmbusError<BUS::Error>> {
+        Ok(Capability::from_raw(self.get_capability(addr).await?))
+    }
+
+    /// Read MFR_PIN_ACCURACY (0xAC) as the ± accuracy of READ_PIN in
+    /// percent. The byte counts in 0.1 % steps, so `0x32` is ±5.0 %.
+    pub async fn get_mfr_pin_accuracy_percent(
+        &mut self,
+        addr: u8,
+    ) -> Result<f32, PmbusError<BUS::Error>> {
+        Ok(self.get_mfr_pin_accuracy(addr).await? as f32 / 10.0)
+    }
+
+    /// Check the host bus clock against the maximum speed in CAPABILITY
+    /// (0x19).
+    ///
+    /// Call at init with the frequency the I2C peripheral is configured for.
+    /// Returns [`PmbusError::BusSpeedTooHigh`] if it exceeds what the device
+    /// advertises.
+    pub async fn verify_bus_speed(
+        &mut self,
+        addr: u8,
+        configured_khz: u32,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let max_khz = self.get_capability_parsed(addr).await?.max_speed_khz;
+        if configured_khz > max_khz as u32 {
+            return Err(PmbusError::BusSpeedTooHigh {
+                configured_khz,
+                max_khz,
+            });
+        }
+        Ok(())
+    }
+
     // =======================================================================
     // Word read/write commands
     // =======================================================================
 
     // Output voltage
-    pmbus_word_rw!(set_vout_command, get_vout_command, VoutCommand);
-    pmbus_word_rw!(set_vout_trim, get_vout_trim, VoutTrim);
+    pmbus_word_rw!(
+        set_vout_command, get_vout_command, VoutCommand,
+        paged: set_vout_command_on_page, get_vout_command_on_page
+    );
+    pmbus_word_rw!(
+        set_vout_trim, get_vout_trim, VoutTrim,
+        paged: set_vout_trim_on_page, get_vout_trim_on_page
+    );
     pmbus_word_rw!(set_vout_cal_offset, get_vout_cal_offset, VoutCalOffset);
-    pmbus_word_rw!(set_vout_max, get_vout_max, VoutMax);
-    pmbus_word_rw!(set_vout_margin_high, get_vout_margin_high, VoutMarginHigh);
-    pmbus_word_rw!(set_vout_margin_low, get_vout_margin_low, VoutMarginLow);
+    pmbus_word_rw!(
+        set_vout_max, get_vout_max, VoutMax,
+        paged: set_vout_max_on_page, get_vout_max_on_page
+    );
+    pmbus_word_rw!(
+        set_vout_margin_high, get_vout_margin_high, VoutMarginHigh,
+        paged: set_vout_margin_high_on_page, get_vout_margin_high_on_page
+    );
+    pmbus_word_rw!(
+        set_vout_margin_low, get_vout_margin_low, VoutMarginLow,
+        paged: set_vout_margin_low_on_page, get_vout_margin_low_on_page
+    );
     pmbus_word_rw!(
         set_vout_transition_rate,
         get_vout_transition_rate,
@@ -310,118 +1675,317 @@ impl<BUS: I2c + 'static> PmbusAdaptor<BUS> {
         get_vout_scale_monitor,
         VoutScaleMonitor
     );
-    pmbus_word_rw!(set_vout_min, get_vout_min, VoutMin);
+    pmbus_word_rw!(
+        set_vout_min, get_vout_min, VoutMin,
+        paged: set_vout_min_on_page, get_vout_min_on_page
+    );
 
     // Power / switching
     pmbus_word_rw!(set_pout_max, get_pout_max, PoutMax);
     pmbus_word_rw!(set_max_duty, get_max_duty, MaxDuty);
     pmbus_word_rw!(set_frequency_switch, get_frequency_switch, FrequencySwitch);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_vin_on, get_vin_on, VinOn);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_vin_off, get_vin_off, VinOff);
     pmbus_word_rw!(set_interleave, get_interleave, Interleave);
     pmbus_word_rw!(set_iout_cal_gain, get_iout_cal_gain, IoutCalGain);
     pmbus_word_rw!(set_iout_cal_offset, get_iout_cal_offset, IoutCalOffset);
 
+    /// Write INTERLEAVE (0x37) from its decoded fields.
+    ///
+    /// Returns [`PmbusError::EncodingError`] if a field is above 15.
+    pub async fn set_interleave_typed(
+        &mut self,
+        addr: u8,
+        interleave: Interleave,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let raw = interleave.to_raw().ok_or(PmbusError::EncodingError)?;
+        self.set_interleave(addr, raw).await
+    }
+
+    /// Read and decode INTERLEAVE (0x37).
+    pub async fn get_interleave_typed(
+        &mut self,
+        addr: u8,
+    ) -> Result<Interleave, PmbusError<BUS::Error>> {
+        Ok(Interleave::from_raw(self.get_interleave(addr).await?))
+    }
+
+    // Current-sense calibration: gain in mΩ, offset in A (LINEAR11)
+    pmbus_linear11_rw!(
+        set_iout_cal_gain_decoded,
+        get_iout_cal_gain_decoded,
+        IoutCalGain
+    );
+    pmbus_linear11_rw!(
+        set_iout_cal_offset_decoded,
+        get_iout_cal_offset_decoded,
+        IoutCalOffset
+    );
+
+    // Output voltage slew rate in V/ms, equivalently mV/µs (LINEAR11)
+    pmbus_linear11_rw!(
+        set_vout_transition_rate_decoded,
+        get_vout_transition_rate_decoded,
+        VoutTransitionRate
+    );
+
+    /// Write MAX_DUTY (0x32) in percent, as LINEAR11.
+    ///
+    /// Returns [`PmbusError::EncodingError`] without writing for values
+    /// outside 0.0..=100.0.
+    pub async fn set_max_duty_percent(
+        &mut self,
+        addr: u8,
+        percent: f32,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        if !(0.0..=100.0).contains(&percent) {
+            return Err(PmbusError::EncodingError);
+        }
+        let raw = Linear11::from_f32(percent).ok_or(PmbusError::EncodingError)?;
+        self.write_cmd_word(addr, CommandCode::MaxDuty, raw.raw())
+            .await
+    }
+
+    /// Read MAX_DUTY (0x32) in percent.
+    pub async fn get_max_duty_percent(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+        let raw = self.read_cmd_word(addr, CommandCode::MaxDuty).await?;
+        Ok(Linear11::from_raw(raw).to_f32())
+    }
+
+    // Input UVLO thresholds in volts (LINEAR11)
+    #[cfg(feature = "limits")]
+    pmbus_linear11_rw!(set_vin_on_volts, get_vin_on_volts, VinOn);
+    #[cfg(feature = "limits")]
+    pmbus_linear11_rw!(set_vin_off_volts, get_vin_off_volts, VinOff);
+
+    /// Program VIN_ON (0x35) and VIN_OFF (0x36) together, in volts.
+    ///
+    /// Returns [`PmbusError::InvalidHysteresis`] without writing anything
+    /// unless `on > off`; a turn-on threshold at or below the turn-off
+    /// threshold makes the converter oscillate around UVLO.
+    #[cfg(feature = "limits")]
+    pub async fn set_vin_uvlo(
+        &mut self,
+        addr: u8,
+        on: f32,
+        off: f32,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let on = Linear11::from_f32(on).ok_or(PmbusError::EncodingError)?;
+        let off = Linear11::from_f32(off).ok_or(PmbusError::EncodingError)?;
+        // Compare after encoding so rounding can't collapse the gap.
+        if on.to_f32() <= off.to_f32() {
+            return Err(PmbusError::InvalidHysteresis);
+        }
+        self.write_cmd_word(addr, CommandCode::VinOff, off.raw())
+            .await?;
+        self.write_cmd_word(addr, CommandCode::VinOn, on.raw())
+            .await
+    }
+
     // Fan commands
+    #[cfg(feature = "fans")]
     pmbus_word_rw!(set_fan_command_1, get_fan_command_1, FanCommand1);
+    #[cfg(feature = "fans")]
     pmbus_word_rw!(set_fan_command_2, get_fan_command_2, FanCommand2);
+    #[cfg(feature = "fans")]
     pmbus_word_rw!(set_fan_command_3, get_fan_command_3, FanCommand3);
+    #[cfg(feature = "fans")]
     pmbus_word_rw!(set_fan_command_4, get_fan_command_4, FanCommand4);
 
+    /// Command fan 1 via FAN_COMMAND_1 (0x3B) as LINEAR11.
+    ///
+    /// FAN_CONFIG_1_2 is read first, and [`PmbusError::FanModeMismatch`] is
+    /// returned if the fan isn't configured for the unit of `speed`.
+    #[cfg(feature = "fans")]
+    pub async fn set_fan_speed_1(
+        &mut self,
+        addr: u8,
+        speed: FanSpeed,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.set_fan_speed(addr, 1, speed).await
+    }
+
+    /// Command fan 2 via FAN_COMMAND_2 (0x3C). See
+    /// [`set_fan_speed_1`](Self::set_fan_speed_1).
+    #[cfg(feature = "fans")]
+    pub async fn set_fan_speed_2(
+        &mut self,
+        addr: u8,
+        speed: FanSpeed,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.set_fan_speed(addr, 2, speed).await
+    }
+
+    /// Command fan 3 via FAN_COMMAND_3 (0x3E), checked against
+    /// FAN_CONFIG_3_4. See [`set_fan_speed_1`](Self::set_fan_speed_1).
+    #[cfg(feature = "fans")]
+    pub async fn set_fan_speed_3(
+        &mut self,
+        addr: u8,
+        speed: FanSpeed,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.set_fan_speed(addr, 3, speed).await
+    }
+
+    /// Command fan 4 via FAN_COMMAND_4 (0x3F), checked against
+    /// FAN_CONFIG_3_4. See [`set_fan_speed_1`](Self::set_fan_speed_1).
+    #[cfg(feature = "fans")]
+    pub async fn set_fan_speed_4(
+        &mut self,
+        addr: u8,
+        speed: FanSpeed,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.set_fan_speed(addr, 4, speed).await
+    }
+
+    /// Shared body of the `set_fan_speed_n` methods; `index` is 1..=4.
+    #[cfg(feature = "fans")]
+    async fn set_fan_speed(
+        &mut self,
+        addr: u8,
+        index: u8,
+        speed: FanSpeed,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let (config_cmd, command) = match index {
+            1 => (CommandCode::FanConfig12, CommandCode::FanCommand1),
+            2 => (CommandCode::FanConfig12, CommandCode::FanCommand2),
+            3 => (CommandCode::FanConfig34, CommandCode::FanCommand3),
+            _ => (CommandCode::FanConfig34, CommandCode::FanCommand4),
+        };
+        let raw = Linear11::from_f32(speed.value()).ok_or(PmbusError::EncodingError)?;
+        let config = self.read_cmd_byte(addr, config_cmd).await?;
+        if fan::commanded_in_rpm(config, index % 2 == 0) != speed.is_rpm() {
+            return Err(PmbusError::FanModeMismatch);
+        }
+        self.write_cmd_word(addr, command, raw.raw()).await
+    }
+
     // Fault/warn limits (word r/w)
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_vout_ov_fault_limit,
         get_vout_ov_fault_limit,
         VoutOvFaultLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_vout_ov_warn_limit,
         get_vout_ov_warn_limit,
         VoutOvWarnLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_vout_uv_warn_limit,
         get_vout_uv_warn_limit,
         VoutUvWarnLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_vout_uv_fault_limit,
         get_vout_uv_fault_limit,
         VoutUvFaultLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_iout_oc_fault_limit,
         get_iout_oc_fault_limit,
         IoutOcFaultLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_iout_oc_lv_fault_limit,
         get_iout_oc_lv_fault_limit,
         IoutOcLvFaultLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_iout_oc_warn_limit,
         get_iout_oc_warn_limit,
         IoutOcWarnLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_iout_uc_fault_limit,
         get_iout_uc_fault_limit,
         IoutUcFaultLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_ot_fault_limit, get_ot_fault_limit, OtFaultLimit);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_ot_warn_limit, get_ot_warn_limit, OtWarnLimit);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_ut_warn_limit, get_ut_warn_limit, UtWarnLimit);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_ut_fault_limit, get_ut_fault_limit, UtFaultLimit);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_vin_ov_fault_limit,
         get_vin_ov_fault_limit,
         VinOvFaultLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_vin_ov_warn_limit, get_vin_ov_warn_limit, VinOvWarnLimit);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_vin_uv_warn_limit, get_vin_uv_warn_limit, VinUvWarnLimit);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_vin_uv_fault_limit,
         get_vin_uv_fault_limit,
         VinUvFaultLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_iin_oc_fault_limit,
         get_iin_oc_fault_limit,
         IinOcFaultLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_iin_oc_warn_limit, get_iin_oc_warn_limit, IinOcWarnLimit);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_power_good_on, get_power_good_on, PowerGoodOn);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_power_good_off, get_power_good_off, PowerGoodOff);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_ton_delay, get_ton_delay, TonDelay);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_ton_rise, get_ton_rise, TonRise);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_ton_max_fault_limit,
         get_ton_max_fault_limit,
         TonMaxFaultLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_toff_delay, get_toff_delay, ToffDelay);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_toff_fall, get_toff_fall, ToffFall);
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_toff_max_warn_limit,
         get_toff_max_warn_limit,
         ToffMaxWarnLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_pout_op_fault_limit,
         get_pout_op_fault_limit,
         PoutOpFaultLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(
         set_pout_op_warn_limit,
         get_pout_op_warn_limit,
         PoutOpWarnLimit
     );
+    #[cfg(feature = "limits")]
     pmbus_word_rw!(set_pin_op_warn_limit, get_pin_op_warn_limit, PinOpWarnLimit);
 
     // Zone / KWH config
     pmbus_word_rw!(set_zone_config, get_zone_config, ZoneConfig);
     pmbus_word_rw!(set_zone_active, get_zone_active, ZoneActive);
+    #[cfg(feature = "energy")]
     pmbus_word_rw!(set_read_kwh_config, get_read_kwh_config, ReadKwhConfig);
 
     // MFR telemetry limits (word r/w)
@@ -439,426 +2003,1908 @@ impl<BUS: I2c + 'static> PmbusAdaptor<BUS> {
     pmbus_word_rw!(set_mfr_max_temp_2, get_mfr_max_temp_2, MfrMaxTemp2);
     pmbus_word_rw!(set_mfr_max_temp_3, get_mfr_max_temp_3, MfrMaxTemp3);
 
+    // Rated maximum temperatures in °C (LINEAR11)
+    pmbus_linear11_rw!(
+        set_mfr_max_temp_1_celsius,
+        get_mfr_max_temp_1_celsius,
+        MfrMaxTemp1
+    );
+    pmbus_linear11_rw!(
+        set_mfr_max_temp_2_celsius,
+        get_mfr_max_temp_2_celsius,
+        MfrMaxTemp2
+    );
+    pmbus_linear11_rw!(
+        set_mfr_max_temp_3_celsius,
+        get_mfr_max_temp_3_celsius,
+        MfrMaxTemp3
+    );
+
     // =======================================================================
     // Read-word only (sensor telemetry)
     // =======================================================================
 
-    pmbus_read_word_only!(read_vin, ReadVin);
-    pmbus_read_word_only!(read_iin, ReadIin);
-    pmbus_read_word_only!(read_vcap, ReadVcap);
-    pmbus_read_word_only!(read_vout, ReadVout);
-    pmbus_read_word_only!(read_iout, ReadIout);
-    pmbus_read_word_only!(read_temperature_1, ReadTemperature1);
-    pmbus_read_word_only!(read_temperature_2, ReadTemperature2);
-    pmbus_read_word_only!(read_temperature_3, ReadTemperature3);
-    pmbus_read_word_only!(read_fan_speed_1, ReadFanSpeed1);
-    pmbus_read_word_only!(read_fan_speed_2, ReadFanSpeed2);
-    pmbus_read_word_only!(read_fan_speed_3, ReadFanSpeed3);
-    pmbus_read_word_only!(read_fan_speed_4, ReadFanSpeed4);
-    pmbus_read_word_only!(read_duty_cycle, ReadDutyCycle);
-    pmbus_read_word_only!(read_frequency, ReadFrequency);
-    pmbus_read_word_only!(read_pout, ReadPout);
-    pmbus_read_word_only!(read_pin, ReadPin);
+    pmbus_read_word_only!(read_vin, ReadVin, paged: read_vin_on_page);
+    pmbus_read_word_only!(read_iin, ReadIin, paged: read_iin_on_page);
+    pmbus_read_word_only!(read_vcap, ReadVcap, paged: read_vcap_on_page);
+    pmbus_read_word_only!(read_vout, ReadVout, paged: read_vout_on_page);
+    pmbus_read_word_only!(read_iout, ReadIout, paged: read_iout_on_page);
+    pmbus_read_word_only!(read_temperature_1, ReadTemperature1, paged: read_temperature_1_on_page);
+    pmbus_read_word_only!(read_temperature_2, ReadTemperature2, paged: read_temperature_2_on_page);
+    pmbus_read_word_only!(read_temperature_3, ReadTemperature3, paged: read_temperature_3_on_page);
+    #[cfg(feature = "fans")]
+    pmbus_read_word_only!(read_fan_speed_1, ReadFanSpeed1, paged: read_fan_speed_1_on_page);
+    #[cfg(feature = "fans")]
+    pmbus_read_word_only!(read_fan_speed_2, ReadFanSpeed2, paged: read_fan_speed_2_on_page);
+    #[cfg(feature = "fans")]
+    pmbus_read_word_only!(read_fan_speed_3, ReadFanSpeed3, paged: read_fan_speed_3_on_page);
+    #[cfg(feature = "fans")]
+    pmbus_read_word_only!(read_fan_speed_4, ReadFanSpeed4, paged: read_fan_speed_4_on_page);
+    pmbus_read_word_only!(read_duty_cycle, ReadDutyCycle, paged: read_duty_cycle_on_page);
+    pmbus_read_word_only!(read_frequency, ReadFrequency, paged: read_frequency_on_page);
+    pmbus_read_word_only!(read_pout, ReadPout, paged: read_pout_on_page);
+    pmbus_read_word_only!(read_pin, ReadPin, paged: read_pin_on_page);
 
     // =======================================================================
-    // Block read/write commands
+    // Decoded telemetry (LINEAR11)
     // =======================================================================
 
-    pmbus_block_rw!(set_mfr_id, get_mfr_id, MfrId);
-    pmbus_block_rw!(set_mfr_model, get_mfr_model, MfrModel);
-    pmbus_block_rw!(set_mfr_revision, get_mfr_revision, MfrRevision);
-    pmbus_block_rw!(set_mfr_location, get_mfr_location, MfrLocation);
-    pmbus_block_rw!(set_mfr_date, get_mfr_date, MfrDate);
-    pmbus_block_rw!(set_mfr_serial, get_mfr_serial, MfrSerial);
-    pmbus_block_read_only!(get_app_profile_support, AppProfileSupport);
-    pmbus_block_read_only!(get_ic_device_id, IcDeviceId);
-    pmbus_block_read_only!(get_ic_device_rev, IcDeviceRev);
-    pmbus_block_read_only!(get_mfr_efficiency_ll, MfrEfficiencyLl);
-    pmbus_block_read_only!(get_mfr_efficiency_hl, MfrEfficiencyHl);
-    pmbus_block_read_only!(read_ein, ReadEin);
-    pmbus_block_read_only!(read_eout, ReadEout);
+    // Methods are named after the unit they return; the `*_f32` names some
+    // drivers expect are generated alongside them.
 
-    // =======================================================================
-    // User data — indexed block read/write
-    // =======================================================================
+    // Power is signed: a bidirectional converter that is sinking power on a
+    // rail (e.g. a discharging battery port) reports negative watts.
+    pmbus_read_linear11!(read_pout_watts, read_pout_f32, ReadPout);
+    pmbus_read_linear11!(read_pin_watts, read_pin_f32, ReadPin);
 
-    /// Write user data block at the given index (0-15).
-    pub async fn set_user_data(
-        &mut self,
-        addr: u8,
-        index: u8,
-        data: &[u8],
-    ) -> Result<(), BUS::Error> {
-        let code = CommandCode::UserData00.code() + (index & 0x0F);
-        self.smbus.block_write(addr, code, data).await
-    }
+    pmbus_read_linear11!(read_vin_volts, read_vin_f32, ReadVin);
+    pmbus_read_linear11!(read_vcap_volts, read_vcap_f32, ReadVcap);
 
-    /// Read user data block at the given index (0-15).
-    pub async fn get_user_data(&mut self, addr: u8, index: u8) -> Result<Vec<u8, 32>, BUS::Error> {
-        let code = CommandCode::UserData00.code() + (index & 0x0F);
-        self.smbus.block_read(addr, code).await
-    }
+    pmbus_read_linear11!(
+        read_temperature_1_celsius,
+        read_temperature_1_f32,
+        ReadTemperature1
+    );
+    pmbus_read_linear11!(
+        read_temperature_2_celsius,
+        read_temperature_2_f32,
+        ReadTemperature2
+    );
+    pmbus_read_linear11!(
+        read_temperature_3_celsius,
+        read_temperature_3_f32,
+        ReadTemperature3
+    );
 
-    // =======================================================================
-    // Status registers — typed accessors
-    // =======================================================================
+    // Input current is positive when drawn from the source.
+    pmbus_read_linear11!(read_iin_amps, read_iin_f32, ReadIin);
 
-    /// Read STATUS_BYTE (0x78).
-    pub async fn get_status_byte(&mut self, addr: u8) -> Result<StatusByte, BUS::Error> {
-        let raw = self.read_cmd_byte(addr, CommandCode::StatusByte).await?;
-        Ok(StatusByte::from_raw(raw))
-    }
+    // Fan speeds in RPM.
+    #[cfg(feature = "fans")]
+    pmbus_read_linear11!(read_fan_speed_1_rpm, read_fan_speed_1_f32, ReadFanSpeed1);
+    #[cfg(feature = "fans")]
+    pmbus_read_linear11!(read_fan_speed_2_rpm, read_fan_speed_2_f32, ReadFanSpeed2);
+    #[cfg(feature = "fans")]
+    pmbus_read_linear11!(read_fan_speed_3_rpm, read_fan_speed_3_f32, ReadFanSpeed3);
+    #[cfg(feature = "fans")]
+    pmbus_read_linear11!(read_fan_speed_4_rpm, read_fan_speed_4_f32, ReadFanSpeed4);
 
-    /// Write STATUS_BYTE to clear bits (0x78).
-    pub async fn set_status_byte(
+    pmbus_read_linear11!(read_duty_cycle_percent, read_duty_cycle_f32, ReadDutyCycle);
+
+    // Switching frequency in kHz.
+    pmbus_read_linear11!(read_frequency_khz, read_frequency_f32, ReadFrequency);
+
+    /// Read the sync frequency in kHz from the command chosen with
+    /// [`set_sync_frequency_code`](Self::set_sync_frequency_code).
+    ///
+    /// Returns [`PmbusError::NotReadable`] if no command is configured.
+    pub async fn read_sync_frequency_khz(
         &mut self,
         addr: u8,
-        status: StatusByte,
-    ) -> Result<(), BUS::Error> {
-        self.write_cmd_byte(addr, CommandCode::StatusByte, status.bits())
-            .await
+    ) -> Result<f32, PmbusError<BUS::Error>> {
+        let code = self.sync_frequency_code.ok_or(PmbusError::NotReadable)?;
+        let raw = self
+            .execute_word(Transaction::ReadWord { addr, code })
+            .await?;
+        Ok(Linear11::from_raw(raw).to_f32())
     }
 
-    /// Read STATUS_WORD (0x79).
-    pub async fn get_status_word(&mut self, addr: u8) -> Result<StatusWord, BUS::Error> {
-        let raw = self.read_cmd_word(addr, CommandCode::StatusWord).await?;
-        Ok(StatusWord::from_raw(raw))
+    /// Classify the input supply from READ_VIN, VIN_ON, VIN_OFF and
+    /// STATUS_INPUT. See [`InputState::classify`].
+    #[cfg(feature = "limits")]
+    pub async fn input_state(&mut self, addr: u8) -> Result<InputState, PmbusError<BUS::Error>> {
+        let vin = self.read_vin_volts(addr).await?;
+        let vin_on = self.get_vin_on_volts(addr).await?;
+        let vin_off = self.get_vin_off_volts(addr).await?;
+        let status = self.get_status_input(addr).await?;
+        Ok(InputState::classify(vin, vin_on, vin_off, status))
     }
 
-    /// Write STATUS_WORD to clear bits (0x79).
-    pub async fn set_status_word(
+    /// Read READ_TEMPERATURE_1..3 in °C and return the highest.
+    ///
+    /// Sensors that NACK are taken as unpopulated and skipped. If all three
+    /// NACK, the last NACK is returned.
+    pub async fn read_hottest_temperature(
         &mut self,
         addr: u8,
-        status: StatusWord,
-    ) -> Result<(), BUS::Error> {
-        self.write_cmd_word(addr, CommandCode::StatusWord, status.bits())
-            .await
+    ) -> Result<f32, PmbusError<BUS::Error>> {
+        let mut hottest: Option<f32> = None;
+        let mut nack = None;
+        for cmd in TEMPERATURE_SENSORS {
+            match self.read_telemetry_word(addr, cmd).await {
+                Ok(raw) => {
+                    let celsius = Linear11::from_raw(raw).to_f32();
+                    hottest = Some(hottest.map_or(celsius, |t| t.max(celsius)));
+                }
+                Err(PmbusError::Bus(e)) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => {
+                    nack = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        match (hottest, nack) {
+            (Some(t), _) => Ok(t),
+            (None, Some(e)) => Err(PmbusError::Bus(e)),
+            // Each sensor either answered or NACKed.
+            (None, None) => Err(PmbusError::NotReadable),
+        }
     }
 
-    /// Read STATUS_VOUT (0x7A).
-    pub async fn get_status_vout(&mut self, addr: u8) -> Result<StatusVout, BUS::Error> {
-        let raw = self.read_cmd_byte(addr, CommandCode::StatusVout).await?;
-        Ok(StatusVout::from_raw(raw))
+    /// Read READ_TEMPERATURE_1..3 in °C.
+    ///
+    /// A sensor that NACKs is reported as `None`; any other error aborts
+    /// the read.
+    pub async fn read_temperatures(
+        &mut self,
+        addr: u8,
+    ) -> Result<[Option<f32>; 3], PmbusError<BUS::Error>> {
+        let mut temperatures = [None; 3];
+        for (slot, cmd) in temperatures.iter_mut().zip(TEMPERATURE_SENSORS) {
+            match self.read_telemetry_word(addr, cmd).await {
+                Ok(raw) => *slot = Some(Linear11::from_raw(raw).to_f32()),
+                Err(PmbusError::Bus(e)) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(temperatures)
     }
 
-    /// Write STATUS_VOUT to clear bits (0x7A).
-    pub async fn set_status_vout(
+    /// Read a word telemetry command and return both the raw word and its
+    /// value decoded as `fmt`.
+    ///
+    /// ULINEAR16 uses the VOUT_MODE exponent and DIRECT the read
+    /// coefficients for `cmd`. Other formats return
+    /// [`PmbusError::UnsupportedFormat`] without touching the bus.
+    pub async fn read_raw_and_decoded(
         &mut self,
         addr: u8,
-        status: StatusVout,
-    ) -> Result<(), BUS::Error> {
-        self.write_cmd_byte(addr, CommandCode::StatusVout, status.bits())
-            .await
+        cmd: CommandCode,
+        fmt: DataFormat,
+    ) -> Result<(u16, f32), PmbusError<BUS::Error>> {
+        match fmt {
+            DataFormat::Linear11 => {
+                let raw = self.read_telemetry_word(addr, cmd).await?;
+                Ok((raw, Linear11::from_raw(raw).to_f32()))
+            }
+            DataFormat::ULinear16 => {
+                let exponent = self.vout_exponent(addr).await?;
+                let raw = self.read_telemetry_word(addr, cmd).await?;
+                Ok((raw, decode_vout(raw, exponent)?))
+            }
+            DataFormat::Direct => {
+                let coefficients = self
+                    .get_coefficients(addr, cmd, CoeffDirection::Read)
+                    .await?;
+                let raw = self.read_telemetry_word(addr, cmd).await?;
+                let value = coefficients
+                    .to_f32(raw as i16)
+                    .ok_or(PmbusError::CoefficientOutOfRange)?;
+                Ok((raw, value))
+            }
+            _ => Err(PmbusError::UnsupportedFormat),
+        }
     }
 
-    /// Read STATUS_IOUT (0x7B).
-    pub async fn get_status_iout(&mut self, addr: u8) -> Result<StatusIout, BUS::Error> {
-        let raw = self.read_cmd_byte(addr, CommandCode::StatusIout).await?;
-        Ok(StatusIout::from_raw(raw))
+    /// Read READ_IOUT (0x8C) in amps, positive while sourcing.
+    ///
+    /// LINEAR11 is signed, so reverse current reads negative. The sign is
+    /// flipped when [`set_invert_iout`](Self::set_invert_iout) is enabled.
+    pub async fn read_iout_amps(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+        let raw = self
+            .read_telemetry_word(addr, CommandCode::ReadIout)
+            .await?;
+        let amps = Linear11::from_raw(raw).to_f32();
+        Ok(if self.invert_iout { -amps } else { amps })
     }
 
-    /// Write STATUS_IOUT to clear bits (0x7B).
-    pub async fn set_status_iout(
-        &mut self,
-        addr: u8,
-        status: StatusIout,
-    ) -> Result<(), BUS::Error> {
-        self.write_cmd_byte(addr, CommandCode::StatusIout, status.bits())
-            .await
+    /// Same as [`read_iout_amps`](Self::read_iout_amps).
+    pub async fn read_iout_f32(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+        self.read_iout_amps(addr).await
     }
 
-    /// Read STATUS_INPUT (0x7C).
-    pub async fn get_status_input(&mut self, addr: u8) -> Result<StatusInput, BUS::Error> {
-        let raw = self.read_cmd_byte(addr, CommandCode::StatusInput).await?;
-        Ok(StatusInput::from_raw(raw))
+    /// Read READ_IOUT (0x8C) and classify the current direction.
+    ///
+    /// Uses [`read_iout_amps`](Self::read_iout_amps), so the
+    /// [`set_invert_iout`](Self::set_invert_iout) setting applies, and
+    /// [`CurrentDirection::DEFAULT_DEADBAND_AMPS`] around zero.
+    pub async fn read_iout_direction(
+        &mut self,
+        addr: u8,
+    ) -> Result<CurrentDirection, PmbusError<BUS::Error>> {
+        let amps = self.read_iout_amps(addr).await?;
+        Ok(CurrentDirection::classify(
+            amps,
+            CurrentDirection::DEFAULT_DEADBAND_AMPS,
+        ))
     }
 
-    /// Write STATUS_INPUT to clear bits (0x7C).
-    pub async fn set_status_input(
+    /// Write the six start-up/shut-down timing registers, in milliseconds.
+    ///
+    /// Every value is encoded before anything is written, so an
+    /// unrepresentable value leaves the device untouched.
+    #[cfg(feature = "limits")]
+    pub async fn apply_timing_limits(
         &mut self,
         addr: u8,
-        status: StatusInput,
-    ) -> Result<(), BUS::Error> {
-        self.write_cmd_byte(addr, CommandCode::StatusInput, status.bits())
-            .await
+        limits: &TimingLimits,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        for (cmd, raw) in encode_timing_limits(limits)? {
+            self.write_cmd_word(addr, cmd, raw).await?;
+        }
+        Ok(())
     }
 
-    /// Read STATUS_TEMPERATURE (0x7D).
-    pub async fn get_status_temperature(
+    /// Read the six start-up/shut-down timing registers, in milliseconds.
+    #[cfg(feature = "limits")]
+    pub async fn read_timing_limits(
         &mut self,
         addr: u8,
-    ) -> Result<StatusTemperature, BUS::Error> {
-        let raw = self
-            .read_cmd_byte(addr, CommandCode::StatusTemperature)
-            .await?;
-        Ok(StatusTemperature::from_raw(raw))
+    ) -> Result<TimingLimits, PmbusError<BUS::Error>> {
+        Ok(TimingLimits {
+            ton_delay: self.read_linear11(addr, CommandCode::TonDelay).await?,
+            ton_rise: self.read_linear11(addr, CommandCode::TonRise).await?,
+            ton_max_fault_limit: self
+                .read_linear11(addr, CommandCode::TonMaxFaultLimit)
+                .await?,
+            toff_delay: self.read_linear11(addr, CommandCode::ToffDelay).await?,
+            toff_fall: self.read_linear11(addr, CommandCode::ToffFall).await?,
+            toff_max_warn_limit: self
+                .read_linear11(addr, CommandCode::ToffMaxWarnLimit)
+                .await?,
+        })
     }
 
-    /// Write STATUS_TEMPERATURE to clear bits (0x7D).
-    pub async fn set_status_temperature(
+    #[cfg(feature = "limits")]
+    async fn read_linear11(
         &mut self,
         addr: u8,
-        status: StatusTemperature,
-    ) -> Result<(), BUS::Error> {
-        self.write_cmd_byte(addr, CommandCode::StatusTemperature, status.bits())
-            .await
+        cmd: CommandCode,
+    ) -> Result<f32, PmbusError<BUS::Error>> {
+        let raw = self.read_cmd_word(addr, cmd).await?;
+        Ok(Linear11::from_raw(raw).to_f32())
     }
 
-    /// Read STATUS_CML (0x7E).
-    pub async fn get_status_cml(&mut self, addr: u8) -> Result<StatusCml, BUS::Error> {
-        let raw = self.read_cmd_byte(addr, CommandCode::StatusCml).await?;
-        Ok(StatusCml::from_raw(raw))
-    }
+    // =======================================================================
+    // Decoded output voltage (VOUT_MODE exponent)
+    // =======================================================================
 
-    /// Write STATUS_CML to clear bits (0x7E).
-    pub async fn set_status_cml(&mut self, addr: u8, status: StatusCml) -> Result<(), BUS::Error> {
-        self.write_cmd_byte(addr, CommandCode::StatusCml, status.bits())
-            .await
+    /// Read VOUT_MODE and return the ULINEAR16 exponent.
+    async fn vout_exponent(&mut self, addr: u8) -> Result<i8, PmbusError<BUS::Error>> {
+        exponent_of(self.get_vout_mode(addr).await?)
     }
 
-    /// Read STATUS_OTHER (0x7F).
-    pub async fn get_status_other(&mut self, addr: u8) -> Result<StatusOther, BUS::Error> {
-        let raw = self.read_cmd_byte(addr, CommandCode::StatusOther).await?;
-        Ok(StatusOther::from_raw(raw))
+    /// Like [`vout_exponent`](Self::vout_exponent), but also rejects relative
+    /// mode, where VOUT_COMMAND is a margin rather than an absolute voltage.
+    async fn vout_command_exponent(&mut self, addr: u8) -> Result<i8, PmbusError<BUS::Error>> {
+        let mode = self.get_vout_mode(addr).await?;
+        if mode.relative {
+            return Err(PmbusError::RelativeModeUnsupported);
+        }
+        exponent_of(mode)
     }
 
-    /// Write STATUS_OTHER to clear bits (0x7F).
-    pub async fn set_status_other(
+    /// Write VOUT_COMMAND (0x21) in volts, using the VOUT_MODE exponent.
+    ///
+    /// Returns [`PmbusError::RelativeModeUnsupported`] if VOUT_MODE has the
+    /// relative bit set, since writing an absolute voltage there would be
+    /// read by the device as a margin.
+    pub async fn set_vout_command_volts(
         &mut self,
         addr: u8,
-        status: StatusOther,
-    ) -> Result<(), BUS::Error> {
-        self.write_cmd_byte(addr, CommandCode::StatusOther, status.bits())
+        volts: f32,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let exponent = self.vout_command_exponent(addr).await?;
+        self.write_vout_command_with_exponent(addr, volts, exponent)
             .await
     }
 
-    /// Read STATUS_MFR_SPECIFIC (0x80).
-    pub async fn get_status_mfr_specific(&mut self, addr: u8) -> Result<u8, BUS::Error> {
-        self.read_cmd_byte(addr, CommandCode::StatusMfrSpecific)
+    async fn write_vout_command_with_exponent(
+        &mut self,
+        addr: u8,
+        volts: f32,
+        exponent: i8,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let raw = ULinear16::from_f32(volts, exponent).ok_or(PmbusError::EncodingError)?;
+        self.write_cmd_word(addr, CommandCode::VoutCommand, raw.raw())
             .await
     }
 
-    /// Write STATUS_MFR_SPECIFIC to clear bits (0x80).
-    pub async fn set_status_mfr_specific(&mut self, addr: u8, data: u8) -> Result<(), BUS::Error> {
-        self.write_cmd_byte(addr, CommandCode::StatusMfrSpecific, data)
-            .await
+    /// Read VOUT_MAX (0x24) in volts, using the VOUT_MODE exponent.
+    pub async fn get_vout_max_volts(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+        self.read_absolute_volts(addr, CommandCode::VoutMax).await
     }
 
-    /// Read STATUS_FANS_1_2 (0x81).
-    pub async fn get_status_fans_12(&mut self, addr: u8) -> Result<StatusFans12, BUS::Error> {
-        let raw = self.read_cmd_byte(addr, CommandCode::StatusFans12).await?;
-        Ok(StatusFans12::from_raw(raw))
+    /// Read VOUT_MIN (0x2B) in volts, using the VOUT_MODE exponent.
+    pub async fn get_vout_min_volts(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+        self.read_absolute_volts(addr, CommandCode::VoutMin).await
     }
 
-    /// Write STATUS_FANS_1_2 to clear bits (0x81).
-    pub async fn set_status_fans_12(
+    /// Write POWER_GOOD_ON (0x5E) in volts.
+    ///
+    /// Like VOUT_COMMAND, and unlike most thresholds, the power-good
+    /// thresholds are ULINEAR16 with the VOUT_MODE exponent.
+    #[cfg(feature = "limits")]
+    pub async fn set_power_good_on_volts(
         &mut self,
         addr: u8,
-        status: StatusFans12,
-    ) -> Result<(), BUS::Error> {
-        self.write_cmd_byte(addr, CommandCode::StatusFans12, status.bits())
+        volts: f32,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_absolute_volts(addr, CommandCode::PowerGoodOn, volts)
             .await
     }
 
-    /// Read STATUS_FANS_3_4 (0x82).
-    pub async fn get_status_fans_34(&mut self, addr: u8) -> Result<StatusFans34, BUS::Error> {
-        let raw = self.read_cmd_byte(addr, CommandCode::StatusFans34).await?;
-        Ok(StatusFans34::from_raw(raw))
+    /// Read POWER_GOOD_ON (0x5E) in volts: the output voltage at which
+    /// POWER_GOOD is asserted.
+    #[cfg(feature = "limits")]
+    pub async fn get_power_good_on_volts(
+        &mut self,
+        addr: u8,
+    ) -> Result<f32, PmbusError<BUS::Error>> {
+        self.read_absolute_volts(addr, CommandCode::PowerGoodOn)
+            .await
     }
 
-    /// Write STATUS_FANS_3_4 to clear bits (0x82).
-    pub async fn set_status_fans_34(
+    /// Write POWER_GOOD_OFF (0x5F) in volts.
+    #[cfg(feature = "limits")]
+    pub async fn set_power_good_off_volts(
         &mut self,
         addr: u8,
-        status: StatusFans34,
-    ) -> Result<(), BUS::Error> {
-        self.write_cmd_byte(addr, CommandCode::StatusFans34, status.bits())
+        volts: f32,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_absolute_volts(addr, CommandCode::PowerGoodOff, volts)
             .await
     }
 
-    // =======================================================================
-    // Special commands — manual implementations
-    // =======================================================================
+    /// Read POWER_GOOD_OFF (0x5F) in volts: the output voltage below which
+    /// POWER_GOOD is deasserted.
+    #[cfg(feature = "limits")]
+    pub async fn get_power_good_off_volts(
+        &mut self,
+        addr: u8,
+    ) -> Result<f32, PmbusError<BUS::Error>> {
+        self.read_absolute_volts(addr, CommandCode::PowerGoodOff)
+            .await
+    }
 
-    /// Read VOUT_MODE (0x20) and parse into `VoutMode`.
-    pub async fn get_vout_mode(&mut self, addr: u8) -> Result<VoutMode, BUS::Error> {
-        let raw = self.read_cmd_byte(addr, CommandCode::VoutMode).await?;
-        Ok(VoutMode::from_raw(raw))
+    /// Read an absolute output-voltage register as ULINEAR16 volts.
+    async fn read_absolute_volts(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+    ) -> Result<f32, PmbusError<BUS::Error>> {
+        let exponent = self.vout_command_exponent(addr).await?;
+        let raw = self.read_cmd_word(addr, cmd).await?;
+        decode_vout(raw, exponent)
     }
 
-    /// Write VOUT_MODE (0x20) from a `VoutMode` value.
-    pub async fn set_vout_mode(&mut self, addr: u8, mode: VoutMode) -> Result<(), BUS::Error> {
-        self.write_cmd_byte(addr, CommandCode::VoutMode, mode.to_raw())
-            .await
+    /// Write an absolute output-voltage register as ULINEAR16 volts.
+    #[cfg(feature = "limits")]
+    async fn write_absolute_volts(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+        volts: f32,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let exponent = self.vout_command_exponent(addr).await?;
+        let raw = ULinear16::from_f32(volts, exponent).ok_or(PmbusError::EncodingError)?;
+        self.write_cmd_word(addr, cmd, raw.raw()).await
     }
 
-    /// Read COEFFICIENTS (0x30) using block read/write process call.
-    ///
-    /// `query` is the 1-byte code identifying which coefficient set to read.
-    pub async fn get_coefficients(
+    /// Like [`set_vout_command_volts`](Self::set_vout_command_volts), but
+    /// first reads VOUT_MIN and VOUT_MAX and returns
+    /// [`PmbusError::VoltageOutOfBounds`] instead of writing a voltage the
+    /// device would clamp.
+    pub async fn set_vout_command_volts_checked(
         &mut self,
         addr: u8,
-        query: u8,
-    ) -> Result<DirectCoefficients, PmbusError<BUS::Error>> {
-        let resp = self
-            .block_process_call_cmd(addr, CommandCode::Coefficients, &[query])
-            .await?;
-        // Response: [byte_count, m_low, m_high, b_low, b_high, r]
-        if resp.len() < 6 {
-            return Err(PmbusError::InvalidResponseLength);
+        volts: f32,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let exponent = self.vout_command_exponent(addr).await?;
+        let max = decode_vout(
+            self.read_cmd_word(addr, CommandCode::VoutMax).await?,
+            exponent,
+        )?;
+        let min = decode_vout(
+            self.read_cmd_word(addr, CommandCode::VoutMin).await?,
+            exponent,
+        )?;
+        if !(min..=max).contains(&volts) {
+            return Err(PmbusError::VoltageOutOfBounds {
+                requested: volts,
+                min,
+                max,
+            });
         }
-        DirectCoefficients::from_coefficients_response(&resp[1..6])
-            .ok_or(PmbusError::InvalidResponseLength)
+        self.write_vout_command_with_exponent(addr, volts, exponent)
+            .await
     }
 
-    /// Execute QUERY command (0x1A) — asks the device about a command's support.
-    pub async fn query(&mut self, addr: u8, command: u8) -> Result<u8, BUS::Error> {
-        self.smbus
-            .process_call(addr, CommandCode::Query.code(), command as u16)
-            .await
-            .map(|w| w as u8)
+    /// Read READ_VOUT (0x8B) in volts, decoded with the VOUT_MODE exponent.
+    ///
+    /// Reads VOUT_MODE (0x20) first. Returns
+    /// [`PmbusError::UnsupportedVoutMode`] if it selects VID, DIRECT or IEEE
+    /// half, which need [`read_vout`](Self::read_vout) and a format-specific
+    /// decode.
+    pub async fn read_vout_f32(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+        let exponent = self.vout_exponent(addr).await?;
+        self.read_vout_with_exponent(addr, exponent).await
     }
 
-    /// Read SMBALERT_MASK (0x1B) using process call.
-    pub async fn get_smbalert_mask(
+    async fn read_vout_with_exponent(
         &mut self,
         addr: u8,
-        status_register: u8,
-    ) -> Result<u8, BUS::Error> {
-        self.smbus
-            .process_call(
-                addr,
-                CommandCode::SmbalertMask.code(),
-                status_register as u16,
-            )
-            .await
-            .map(|w| w as u8)
+        exponent: i8,
+    ) -> Result<f32, PmbusError<BUS::Error>> {
+        let raw = self
+            .read_telemetry_word(addr, CommandCode::ReadVout)
+            .await?;
+        decode_vout(raw, exponent)
     }
 
-    /// Write SMBALERT_MASK (0x1B).
-    pub async fn set_smbalert_mask(&mut self, addr: u8, data: u16) -> Result<(), BUS::Error> {
-        self.write_cmd_word(addr, CommandCode::SmbalertMask, data)
-            .await
+    /// Read VOUT_COMMAND and READ_VOUT in volts on each of `pages`.
+    ///
+    /// VOUT_MODE is read per page, since rails on one device can use
+    /// different exponents. Fails on the first page whose VOUT_MODE isn't an
+    /// absolute ULINEAR16 format. At most 32 pages are read.
+    pub async fn read_all_vout(
+        &mut self,
+        addr: u8,
+        pages: &[u8],
+    ) -> Result<Vec<(u8, VoutPair), 32>, PmbusError<BUS::Error>> {
+        let mut rails = Vec::new();
+        for &page in pages.iter().take(32) {
+            self.select_page(addr, page).await?;
+            let exponent = self.vout_command_exponent(addr).await?;
+            let commanded = decode_vout(self.get_vout_command(addr).await?, exponent)?;
+            let pair = VoutPair {
+                commanded,
+                measured: self.read_vout_with_exponent(addr, exponent).await?,
+            };
+            let _ = rails.push((page, pair));
+        }
+        Ok(rails)
     }
 
-    /// Read PAGE_PLUS_READ (0x06) — reads a byte from a specific page in one transaction.
-    pub async fn page_plus_read(
+    /// Deviation of READ_VOUT from VOUT_COMMAND on `page`, in percent of
+    /// VOUT_COMMAND: `(measured - commanded) / commanded * 100`.
+    ///
+    /// Negative values mean the output sits below its setpoint, e.g. from
+    /// droop under load. The result isn't finite if VOUT_COMMAND is 0 V.
+    pub async fn vout_regulation_error(
         &mut self,
         addr: u8,
         page: u8,
-        command: u8,
-    ) -> Result<Vec<u8, 32>, BUS::Error> {
-        self.block_process_call_cmd(addr, CommandCode::PagePlusRead, &[page, command])
-            .await
+    ) -> Result<f32, PmbusError<BUS::Error>> {
+        self.select_page(addr, page).await?;
+        let exponent = self.vout_command_exponent(addr).await?;
+        let commanded = decode_vout(self.get_vout_command(addr).await?, exponent)?;
+        let measured = self.read_vout_with_exponent(addr, exponent).await?;
+        Ok((measured - commanded) / commanded * 100.0)
     }
 
-    /// Write PAGE_PLUS_WRITE (0x05) — writes data to a specific page in one transaction.
-    pub async fn page_plus_write(&mut self, addr: u8, data: &[u8]) -> Result<(), BUS::Error> {
-        self.block_write_cmd(addr, CommandCode::PagePlusWrite, data)
-            .await
+    /// Read the telemetry of the rail on `page`.
+    ///
+    /// PAGE is only written if it differs from the cached page, and VOUT_MODE
+    /// is only read if it isn't [cached](Self::cache_vout_mode) yet.
+    pub async fn read_telemetry(
+        &mut self,
+        addr: u8,
+        page: u8,
+    ) -> Result<RailTelemetry, PmbusError<BUS::Error>> {
+        self.select_page(addr, page).await?;
+        let exponent = self.cached_vout_exponent(addr).await?;
+        Ok(RailTelemetry {
+            vin: self.read_vin_volts(addr).await?,
+            vout: self.read_vout_with_exponent(addr, exponent).await?,
+            iout: self.read_iout_amps(addr).await?,
+            temperature: self.read_temperature_1_celsius(addr).await?,
+            pout: self.read_pout_watts(addr).await?,
+        })
     }
 
-    /// Read KWH_IN (0x83) — 4-byte (32-bit) read via I2C write_read.
-    pub async fn read_kwh_in(&mut self, addr: u8) -> Result<u32, BUS::Error> {
-        let mut buf = [0u8; 4];
-        self.smbus
-            .write_read(addr, &[CommandCode::ReadKwhIn.code()], &mut buf)
-            .await?;
-        Ok(u32::from_le_bytes(buf))
+    /// Read the telemetry of each of `pages`, in order.
+    ///
+    /// A fixed-size counterpart to [`read_telemetry`](Self::read_telemetry)
+    /// for boards with a known rail count. Fails on the first rail that
+    /// can't be read.
+    pub async fn read_multi_rail<const N: usize>(
+        &mut self,
+        addr: u8,
+        pages: [u8; N],
+    ) -> Result<[RailTelemetry; N], PmbusError<BUS::Error>> {
+        let mut rails = [RailTelemetry::default(); N];
+        for (rail, page) in rails.iter_mut().zip(pages) {
+            *rail = self.read_telemetry(addr, page).await?;
+        }
+        Ok(rails)
     }
 
-    /// Read KWH_OUT (0x84) — 4-byte (32-bit) read via I2C write_read.
-    pub async fn read_kwh_out(&mut self, addr: u8) -> Result<u32, BUS::Error> {
-        let mut buf = [0u8; 4];
-        self.smbus
-            .write_read(addr, &[CommandCode::ReadKwhOut.code()], &mut buf)
+    /// Set VOUT_COMMAND in volts, then poll READ_VOUT until it settles.
+    ///
+    /// READ_VOUT is sampled every millisecond until it is within `tolerance`
+    /// volts of the target, and the settled reading is returned. Returns
+    /// [`PmbusError::SettleTimeout`] if that doesn't happen within
+    /// `timeout_ms`.
+    pub async fn set_vout_and_settle(
+        &mut self,
+        addr: u8,
+        volts: f32,
+        tolerance: f32,
+        timeout_ms: u32,
+        delay: &mut impl DelayNs,
+    ) -> Result<f32, PmbusError<BUS::Error>> {
+        let exponent = self.vout_command_exponent(addr).await?;
+        self.write_vout_command_with_exponent(addr, volts, exponent)
             .await?;
-        Ok(u32::from_le_bytes(buf))
+        let mut elapsed_ms = 0;
+        loop {
+            let vout = self.read_vout_with_exponent(addr, exponent).await?;
+            if (vout - volts).abs() <= tolerance {
+                return Ok(vout);
+            }
+            if elapsed_ms >= timeout_ms {
+                return Err(PmbusError::SettleTimeout);
+            }
+            delay.delay_ms(1).await;
+            elapsed_ms += 1;
+        }
     }
 
-    // =======================================================================
-    // Raw methods for manufacturer-specific codes
-    // =======================================================================
-
-    /// Read a byte from any command code.
-    pub async fn raw_read_byte(&mut self, addr: u8, code: u8) -> Result<u8, BUS::Error> {
-        self.smbus.read_byte(addr, code).await
+    /// Apply a complete rail configuration on `page`.
+    ///
+    /// If WRITE_PROTECT is set it is cleared first, since a protected device
+    /// would also reject the PAGE write, and restored at the end, even on
+    /// error. Every value, timing included, is then encoded before any
+    /// configuration register is written, so a value that doesn't fit
+    /// leaves the rail untouched. Writes go out in an order that keeps the
+    /// rail protected throughout: VOUT_MAX, fault responses, limits, timing,
+    /// margins, VOUT_COMMAND, and ON_OFF_CONFIG last since it can enable the
+    /// output. With [`RailConfig::recheck_write_protect`] set, WRITE_PROTECT
+    /// is re-read after each group of writes. With [`RailConfig::store`] set,
+    /// STORE_USER_ALL is issued while WRITE_PROTECT is still clear.
+    ///
+    /// If both the configuration and restoring WRITE_PROTECT fail, the
+    /// configuration error is returned.
+    #[cfg(feature = "limits")]
+    pub async fn configure_rail(
+        &mut self,
+        addr: u8,
+        page: u8,
+        config: &RailConfig<'_>,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let protect = self.get_write_protect(addr).await?;
+        if protect != 0 {
+            self.set_write_protect(addr, 0).await?;
+        }
+        let result = self.configure_rail_unprotected(addr, page, config).await;
+        let restored = if protect != 0 {
+            self.set_write_protect(addr, protect).await
+        } else {
+            Ok(())
+        };
+        result.and(restored)
     }
 
-    /// Write a byte to any command code.
-    pub async fn raw_write_byte(&mut self, addr: u8, code: u8, data: u8) -> Result<(), BUS::Error> {
-        self.smbus.write_byte(addr, code, data).await
+    /// [`configure_rail`](Self::configure_rail) with WRITE_PROTECT already
+    /// cleared.
+    #[cfg(feature = "limits")]
+    async fn configure_rail_unprotected(
+        &mut self,
+        addr: u8,
+        page: u8,
+        config: &RailConfig<'_>,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.select_page(addr, page).await?;
+        let exponent = self.vout_command_exponent(addr).await?;
+        for volts in [
+            config.vout_max,
+            config.vout_margin_high,
+            config.vout_margin_low,
+        ]
+        .into_iter()
+        .flatten()
+        .chain([config.vout_command])
+        {
+            encode_vout::<BUS::Error>(volts, exponent)?;
+        }
+        for &(cmd, value) in config.limits {
+            encode_limit(cmd, value, exponent)?;
+        }
+        if let Some(timing) = &config.timing {
+            encode_timing_limits::<BUS::Error>(timing)?;
+        }
+        self.write_rail_config(addr, config, exponent).await?;
+        if config.store {
+            self.store_user_all(addr).await?;
+        }
+        Ok(())
     }
 
-    /// Read a word from any command code.
-    pub async fn raw_read_word(&mut self, addr: u8, code: u8) -> Result<u16, BUS::Error> {
-        self.smbus.read_word(addr, code).await
+    /// The writes of [`configure_rail`](Self::configure_rail), in order.
+    #[cfg(feature = "limits")]
+    async fn write_rail_config(
+        &mut self,
+        addr: u8,
+        config: &RailConfig<'_>,
+        exponent: i8,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let recheck = config.recheck_write_protect;
+        if let Some(volts) = config.vout_max {
+            self.write_cmd_word(addr, CommandCode::VoutMax, encode_vout(volts, exponent)?)
+                .await?;
+            self.check_write_protect(addr, recheck).await?;
+        }
+        for &(cmd, response) in config.fault_responses {
+            self.write_cmd_byte(addr, cmd, response).await?;
+        }
+        self.check_write_protect(addr, recheck).await?;
+        for &(cmd, value) in config.limits {
+            self.write_cmd_word(addr, cmd, encode_limit(cmd, value, exponent)?)
+                .await?;
+        }
+        self.check_write_protect(addr, recheck).await?;
+        if let Some(timing) = &config.timing {
+            self.apply_timing_limits(addr, timing).await?;
+            self.check_write_protect(addr, recheck).await?;
+        }
+        if let Some(volts) = config.vout_margin_high {
+            self.write_cmd_word(
+                addr,
+                CommandCode::VoutMarginHigh,
+                encode_vout(volts, exponent)?,
+            )
+            .await?;
+        }
+        if let Some(volts) = config.vout_margin_low {
+            self.write_cmd_word(
+                addr,
+                CommandCode::VoutMarginLow,
+                encode_vout(volts, exponent)?,
+            )
+            .await?;
+        }
+        self.check_write_protect(addr, recheck).await?;
+        self.write_vout_command_with_exponent(addr, config.vout_command, exponent)
+            .await?;
+        self.check_write_protect(addr, recheck).await?;
+        if let Some(cfg) = config.on_off_config {
+            self.set_on_off_config(addr, cfg).await?;
+            self.check_write_protect(addr, recheck).await?;
+        }
+        Ok(())
     }
 
-    /// Write a word to any command code.
-    pub async fn raw_write_word(
+    /// With `enabled`, read WRITE_PROTECT and return
+    /// [`PmbusError::WriteProtected`] if it is set.
+    #[cfg(feature = "limits")]
+    async fn check_write_protect(
         &mut self,
         addr: u8,
-        code: u8,
-        data: u16,
-    ) -> Result<(), BUS::Error> {
-        self.smbus.write_word(addr, code, data).await
+        enabled: bool,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        if enabled && self.get_write_protect(addr).await? != 0 {
+            return Err(PmbusError::WriteProtected);
+        }
+        Ok(())
     }
 
-    /// Block read from any command code.
-    pub async fn raw_block_read(&mut self, addr: u8, code: u8) -> Result<Vec<u8, 32>, BUS::Error> {
-        self.smbus.block_read(addr, code).await
+    /// Write any fault/warning limit or threshold in engineering units.
+    ///
+    /// Output-voltage limits (VOUT_*_LIMIT, IOUT_OC_LV_FAULT_LIMIT,
+    /// POWER_GOOD_ON/OFF) are encoded as ULINEAR16 with the VOUT_MODE
+    /// exponent; all others as LINEAR11. Returns [`PmbusError::NotALimit`]
+    /// if `cmd` isn't a limit.
+    #[cfg(feature = "limits")]
+    pub async fn set_limit(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+        value: f32,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let exponent = match limit_format(cmd).ok_or(PmbusError::NotALimit)? {
+            LimitFormat::Vout => self.vout_command_exponent(addr).await?,
+            LimitFormat::Linear11 => 0,
+        };
+        let raw = encode_limit(cmd, value, exponent)?;
+        self.write_cmd_word(addr, cmd, raw).await
     }
 
-    /// Block write to any command code.
-    pub async fn raw_block_write(
+    /// Read any fault/warning limit or threshold in engineering units.
+    ///
+    /// See [`set_limit`](Self::set_limit) for how the format is chosen.
+    #[cfg(feature = "limits")]
+    pub async fn get_limit(
         &mut self,
         addr: u8,
-        code: u8,
-        data: &[u8],
-    ) -> Result<(), BUS::Error> {
-        self.smbus.block_write(addr, code, data).await
+        cmd: CommandCode,
+    ) -> Result<f32, PmbusError<BUS::Error>> {
+        match limit_format(cmd).ok_or(PmbusError::NotALimit)? {
+            LimitFormat::Vout => {
+                let exponent = self.vout_command_exponent(addr).await?;
+                let raw = self.read_cmd_word(addr, cmd).await?;
+                decode_vout(raw, exponent)
+            }
+            LimitFormat::Linear11 => {
+                let raw = self.read_cmd_word(addr, cmd).await?;
+                Ok(Linear11::from_raw(raw).to_f32())
+            }
+        }
     }
 
-    // =======================================================================
-    // Extended command protocol
-    // =======================================================================
+    /// Write a fault limit and its response together.
+    ///
+    /// Both are encoded before anything is sent, so an unencodable limit
+    /// or response leaves the device untouched.
+    #[cfg(feature = "limits")]
+    async fn set_protection(
+        &mut self,
+        addr: u8,
+        limit_cmd: CommandCode,
+        response_cmd: CommandCode,
+        protection: &Protection,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let response = protection
+            .response
+            .to_raw()
+            .ok_or(PmbusError::EncodingError)?;
+        let exponent = match limit_format(limit_cmd).ok_or(PmbusError::NotALimit)? {
+            LimitFormat::Vout => self.vout_command_exponent(addr).await?,
+            LimitFormat::Linear11 => 0,
+        };
+        let limit = encode_limit(limit_cmd, protection.limit, exponent)?;
+        self.write_cmd_word(addr, limit_cmd, limit).await?;
+        self.write_cmd_byte(addr, response_cmd, response).await
+    }
 
-    /// Extended read byte — sends [prefix, ext_cmd] and reads 1 byte.
-    pub async fn extended_read_byte(
+    #[cfg(feature = "limits")]
+    async fn get_protection(
         &mut self,
         addr: u8,
-        prefix: u8,
-        ext_cmd: u8,
-    ) -> Result<u8, BUS::Error> {
-        let mut buf = [0u8; 1];
-        self.smbus
-            .write_read(addr, &[prefix, ext_cmd], &mut buf)
-            .await?;
-        Ok(buf[0])
+        limit_cmd: CommandCode,
+        response_cmd: CommandCode,
+    ) -> Result<Protection, PmbusError<BUS::Error>> {
+        let limit = self.get_limit(addr, limit_cmd).await?;
+        let response = FaultResponse::from_raw(self.read_cmd_byte(addr, response_cmd).await?);
+        Ok(Protection { limit, response })
     }
 
-    /// Extended write byte — sends [prefix, ext_cmd, data].
-    pub async fn extended_write_byte(
+    // Fault limit + response pairs, per protection domain.
+    pmbus_protection!(
+        set_vout_ov_protection,
+        get_vout_ov_protection,
+        VoutOvFaultLimit,
+        VoutOvFaultResponse
+    );
+    pmbus_protection!(
+        set_vout_uv_protection,
+        get_vout_uv_protection,
+        VoutUvFaultLimit,
+        VoutUvFaultResponse
+    );
+    pmbus_protection!(
+        set_iout_oc_protection,
+        get_iout_oc_protection,
+        IoutOcFaultLimit,
+        IoutOcFaultResponse
+    );
+    pmbus_protection!(
+        set_iout_oc_lv_protection,
+        get_iout_oc_lv_protection,
+        IoutOcLvFaultLimit,
+        IoutOcLvFaultResponse
+    );
+    pmbus_protection!(
+        set_iout_uc_protection,
+        get_iout_uc_protection,
+        IoutUcFaultLimit,
+        IoutUcFaultResponse
+    );
+    pmbus_protection!(
+        set_ot_protection,
+        get_ot_protection,
+        OtFaultLimit,
+        OtFaultResponse
+    );
+    pmbus_protection!(
+        set_ut_protection,
+        get_ut_protection,
+        UtFaultLimit,
+        UtFaultResponse
+    );
+    pmbus_protection!(
+        set_vin_ov_protection,
+        get_vin_ov_protection,
+        VinOvFaultLimit,
+        VinOvFaultResponse
+    );
+    pmbus_protection!(
+        set_vin_uv_protection,
+        get_vin_uv_protection,
+        VinUvFaultLimit,
+        VinUvFaultResponse
+    );
+    pmbus_protection!(
+        set_iin_oc_protection,
+        get_iin_oc_protection,
+        IinOcFaultLimit,
+        IinOcFaultResponse
+    );
+
+    /// Read and decode all twelve `*_FAULT_RESPONSE` bytes of the selected
+    /// page.
+    ///
+    /// Stops at the first failing read, so a device that NACKs an
+    /// unimplemented response command fails the whole dump.
+    #[cfg(feature = "limits")]
+    pub async fn read_all_fault_responses(
         &mut self,
         addr: u8,
-        prefix: u8,
-        ext_cmd: u8,
-        data: u8,
-    ) -> Result<(), BUS::Error> {
-        self.smbus.write(addr, &[prefix, ext_cmd, data]).await
+    ) -> Result<FaultResponses, PmbusError<BUS::Error>> {
+        let mut raw = [0u8; 12];
+        let cmds = [
+            CommandCode::VoutOvFaultResponse,
+            CommandCode::VoutUvFaultResponse,
+            CommandCode::IoutOcFaultResponse,
+            CommandCode::IoutOcLvFaultResponse,
+            CommandCode::IoutUcFaultResponse,
+            CommandCode::OtFaultResponse,
+            CommandCode::UtFaultResponse,
+            CommandCode::VinOvFaultResponse,
+            CommandCode::VinUvFaultResponse,
+            CommandCode::IinOcFaultResponse,
+            CommandCode::TonMaxFaultResponse,
+            CommandCode::PoutOpFaultResponse,
+        ];
+        for (slot, cmd) in raw.iter_mut().zip(cmds) {
+            *slot = self.read_cmd_byte(addr, cmd).await?;
+        }
+        let [
+            vout_ov,
+            vout_uv,
+            iout_oc,
+            iout_oc_lv,
+            iout_uc,
+            ot,
+            ut,
+            vin_ov,
+            vin_uv,
+            iin_oc,
+            ton_max,
+            pout_op,
+        ] = raw.map(FaultResponse::from_raw);
+        Ok(FaultResponses {
+            vout_ov,
+            vout_uv,
+            iout_oc,
+            iout_oc_lv,
+            iout_uc,
+            ot,
+            ut,
+            vin_ov,
+            vin_uv,
+            iin_oc,
+            ton_max,
+            pout_op,
+        })
     }
 
-    /// Extended read word — sends [prefix, ext_cmd] and reads 2 bytes (LE).
-    pub async fn extended_read_word(
+    /// Write VOUT_TRIM (0x22) as a signed offset in volts.
+    ///
+    /// Unlike VOUT_COMMAND the trim may be negative; it is written as two's
+    /// complement using the VOUT_MODE exponent.
+    pub async fn set_vout_trim_volts(
+        &mut self,
+        addr: u8,
+        volts: f32,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let exponent = self.vout_exponent(addr).await?;
+        let raw = encode_signed_ulinear16(volts, exponent).ok_or(PmbusError::EncodingError)?;
+        self.write_cmd_word(addr, CommandCode::VoutTrim, raw).await
+    }
+
+    /// Write VOUT_CAL_OFFSET (0x23) as a signed offset in volts.
+    pub async fn set_vout_cal_offset_volts(
+        &mut self,
+        addr: u8,
+        volts: f32,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let exponent = self.vout_exponent(addr).await?;
+        let raw = encode_signed_ulinear16(volts, exponent).ok_or(PmbusError::EncodingError)?;
+        self.write_cmd_word(addr, CommandCode::VoutCalOffset, raw)
+            .await
+    }
+
+    /// Read VOUT_TRIM (0x22) as a signed offset in volts.
+    pub async fn get_vout_trim_volts(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+        let exponent = self.vout_exponent(addr).await?;
+        let raw = self.read_cmd_word(addr, CommandCode::VoutTrim).await?;
+        checked_decode_signed_ulinear16(raw, exponent).ok_or(PmbusError::UnsupportedVoutMode)
+    }
+
+    /// Read VOUT_CAL_OFFSET (0x23) as a signed offset in volts.
+    pub async fn get_vout_cal_offset_volts(
+        &mut self,
+        addr: u8,
+    ) -> Result<f32, PmbusError<BUS::Error>> {
+        let exponent = self.vout_exponent(addr).await?;
+        let raw = self.read_cmd_word(addr, CommandCode::VoutCalOffset).await?;
+        checked_decode_signed_ulinear16(raw, exponent).ok_or(PmbusError::UnsupportedVoutMode)
+    }
+
+    // =======================================================================
+    // Block read/write commands
+    // =======================================================================
+
+    /// Block read `cmd`, returning [`PmbusError::EmptyBlock`] instead of an
+    /// empty block when the count byte is zero.
+    ///
+    /// The plain block getters return empty blocks as they are; use this
+    /// where an empty value would mean the command isn't supported. As
+    /// there, the returned vector starts with the count byte.
+    pub async fn block_read_nonempty(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+    ) -> Result<Vec<u8, 33>, PmbusError<BUS::Error>> {
+        let block = self.block_read_cmd(addr, cmd).await?;
+        if block.first().is_none_or(|&count| count == 0) {
+            return Err(PmbusError::EmptyBlock);
+        }
+        Ok(block)
+    }
+
+    #[cfg(feature = "identification")]
+    pmbus_block_rw!(set_mfr_id, get_mfr_id, MfrId);
+    #[cfg(feature = "identification")]
+    pmbus_block_rw!(set_mfr_model, get_mfr_model, MfrModel);
+    #[cfg(feature = "identification")]
+    pmbus_block_rw!(set_mfr_revision, get_mfr_revision, MfrRevision);
+    #[cfg(feature = "identification")]
+    pmbus_block_rw!(set_mfr_location, get_mfr_location, MfrLocation);
+    #[cfg(feature = "identification")]
+    pmbus_block_rw!(set_mfr_date, get_mfr_date, MfrDate);
+    #[cfg(feature = "identification")]
+    pmbus_block_rw!(set_mfr_serial, get_mfr_serial, MfrSerial);
+    #[cfg(feature = "identification")]
+    pmbus_block_read_only!(get_app_profile_support, AppProfileSupport);
+    #[cfg(feature = "identification")]
+    pmbus_block_read_only!(get_ic_device_id, IcDeviceId);
+    #[cfg(feature = "identification")]
+    pmbus_block_read_only!(get_ic_device_rev, IcDeviceRev);
+    pmbus_block_read_only!(get_mfr_efficiency_ll, MfrEfficiencyLl);
+    pmbus_block_read_only!(get_mfr_efficiency_hl, MfrEfficiencyHl);
+    #[cfg(feature = "energy")]
+    pmbus_block_read_only!(read_ein, ReadEin);
+    #[cfg(feature = "energy")]
+    pmbus_block_read_only!(read_eout, ReadEout);
+
+    /// Read READ_EIN (0x86) as an [`EnergyReading`].
+    ///
+    /// Returns [`PmbusError::InvalidResponseLength`] if fewer than 6 data
+    /// bytes come back.
+    #[cfg(feature = "energy")]
+    pub async fn read_ein_parsed(
+        &mut self,
+        addr: u8,
+    ) -> Result<EnergyReading, PmbusError<BUS::Error>> {
+        let block = self.read_ein(addr).await?;
+        EnergyReading::from_block(block.get(1..).unwrap_or(&[]))
+            .ok_or(PmbusError::InvalidResponseLength)
+    }
+
+    /// Read READ_EOUT (0x87) as an [`EnergyReading`].
+    ///
+    /// Returns [`PmbusError::InvalidResponseLength`] if fewer than 6 data
+    /// bytes come back.
+    #[cfg(feature = "energy")]
+    pub async fn read_eout_parsed(
+        &mut self,
+        addr: u8,
+    ) -> Result<EnergyReading, PmbusError<BUS::Error>> {
+        let block = self.read_eout(addr).await?;
+        EnergyReading::from_block(block.get(1..).unwrap_or(&[]))
+            .ok_or(PmbusError::InvalidResponseLength)
+    }
+
+    /// Read PMBUS_REVISION and the MFR_ID/MODEL/REVISION/SERIAL strings.
+    ///
+    /// A failed PMBUS_REVISION read is returned as an error; any of the MFR
+    /// fields that NACK or come back empty are reported as `None`.
+    #[cfg(feature = "identification")]
+    pub async fn read_identity(
+        &mut self,
+        addr: u8,
+    ) -> Result<DeviceIdentity, PmbusError<BUS::Error>> {
+        let pmbus_revision = PmbusRevision::from_raw(self.get_pmbus_revision(addr).await?);
+        Ok(DeviceIdentity {
+            pmbus_revision,
+            mfr_id: self.read_id_field(addr, CommandCode::MfrId).await,
+            mfr_model: self.read_id_field(addr, CommandCode::MfrModel).await,
+            mfr_revision: self.read_id_field(addr, CommandCode::MfrRevision).await,
+            mfr_serial: self.read_id_field(addr, CommandCode::MfrSerial).await,
+        })
+    }
+
+    /// Read MFR_EFFICIENCY_LL (0xAA), measured at low line, as a curve.
+    pub async fn get_mfr_efficiency_ll_curve(
+        &mut self,
+        addr: u8,
+    ) -> Result<EfficiencyCurve, PmbusError<BUS::Error>> {
+        let block = self.get_mfr_efficiency_ll(addr).await?;
+        EfficiencyCurve::from_block(block_data(&block, 14)?)
+            .ok_or(PmbusError::InvalidResponseLength)
+    }
+
+    /// Read MFR_EFFICIENCY_HL (0xAB), measured at high line, as a curve.
+    pub async fn get_mfr_efficiency_hl_curve(
+        &mut self,
+        addr: u8,
+    ) -> Result<EfficiencyCurve, PmbusError<BUS::Error>> {
+        let block = self.get_mfr_efficiency_hl(addr).await?;
+        EfficiencyCurve::from_block(block_data(&block, 14)?)
+            .ok_or(PmbusError::InvalidResponseLength)
+    }
+
+    /// Read APP_PROFILE_SUPPORT (0x9F) as a set of profile identifiers.
+    #[cfg(feature = "identification")]
+    pub async fn get_app_profiles(
+        &mut self,
+        addr: u8,
+    ) -> Result<AppProfiles, PmbusError<BUS::Error>> {
+        let block = self.get_app_profile_support(addr).await?;
+        // First byte of the block is the SMBus byte count.
+        Ok(AppProfiles::from_block(block.get(1..).unwrap_or(&[])))
+    }
+
+    /// Check that the device at `addr` speaks PMBus.
+    ///
+    /// Reads PMBUS_REVISION (0x98) and returns [`PmbusError::NotPmbus`] if the
+    /// byte doesn't decode to a known revision. Use this before issuing
+    /// PMBus commands to devices found by scanning a mixed bus.
+    pub async fn probe_pmbus(&mut self, addr: u8) -> Result<PmbusRevision, PmbusError<BUS::Error>> {
+        let rev = PmbusRevision::from_raw(self.get_pmbus_revision(addr).await?);
+        if rev.is_plausible() {
+            Ok(rev)
+        } else {
+            Err(PmbusError::NotPmbus)
+        }
+    }
+
+    #[cfg(feature = "identification")]
+    async fn read_id_field(&mut self, addr: u8, cmd: CommandCode) -> Option<IdString> {
+        let block = self.block_read_cmd(addr, cmd).await.ok()?;
+        // First byte of the block is the SMBus byte count.
+        IdString::from_block(block.get(1..)?)
+    }
+
+    // =======================================================================
+    // User data — indexed block read/write
+    // =======================================================================
+
+    /// Write user data block at the given index (0-15).
+    pub async fn set_user_data(
+        &mut self,
+        addr: u8,
+        index: u8,
+        data: &[u8],
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let code = CommandCode::UserData00.code() + (index & 0x0F);
+        self.execute(Transaction::BlockWrite { addr, code, data })
+            .await?;
+        Ok(())
+    }
+
+    /// Read user data block at the given index (0-15).
+    pub async fn get_user_data(
+        &mut self,
+        addr: u8,
+        index: u8,
+    ) -> Result<Vec<u8, 33>, PmbusError<BUS::Error>> {
+        let code = CommandCode::UserData00.code() + (index & 0x0F);
+        self.execute_block(Transaction::BlockRead { addr, code })
+            .await
+    }
+
+    // =======================================================================
+    // Status registers — typed accessors
+    // =======================================================================
+
+    /// Read STATUS_BYTE (0x78).
+    pub async fn get_status_byte(
+        &mut self,
+        addr: u8,
+    ) -> Result<StatusByte, PmbusError<BUS::Error>> {
+        let raw = self.read_status_byte(addr, CommandCode::StatusByte).await?;
+        Ok(StatusByte::from_raw(raw))
+    }
+
+    /// Write STATUS_BYTE to clear bits (0x78).
+    pub async fn set_status_byte(
+        &mut self,
+        addr: u8,
+        status: StatusByte,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_cmd_byte(addr, CommandCode::StatusByte, status.bits())
+            .await
+    }
+
+    /// Read STATUS_WORD (0x79).
+    pub async fn get_status_word(
+        &mut self,
+        addr: u8,
+    ) -> Result<StatusWord, PmbusError<BUS::Error>> {
+        let raw = self.read_status_word(addr, CommandCode::StatusWord).await?;
+        Ok(StatusWord::from_raw(raw))
+    }
+
+    /// Write STATUS_WORD to clear bits (0x79).
+    pub async fn set_status_word(
+        &mut self,
+        addr: u8,
+        status: StatusWord,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_cmd_word(addr, CommandCode::StatusWord, status.bits())
+            .await
+    }
+
+    /// Read STATUS_VOUT (0x7A).
+    pub async fn get_status_vout(
+        &mut self,
+        addr: u8,
+    ) -> Result<StatusVout, PmbusError<BUS::Error>> {
+        let raw = self.read_status_byte(addr, CommandCode::StatusVout).await?;
+        Ok(StatusVout::from_raw(raw))
+    }
+
+    /// Write STATUS_VOUT to clear bits (0x7A).
+    pub async fn set_status_vout(
+        &mut self,
+        addr: u8,
+        status: StatusVout,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_cmd_byte(addr, CommandCode::StatusVout, status.bits())
+            .await
+    }
+
+    /// Read STATUS_IOUT (0x7B).
+    pub async fn get_status_iout(
+        &mut self,
+        addr: u8,
+    ) -> Result<StatusIout, PmbusError<BUS::Error>> {
+        let raw = self.read_status_byte(addr, CommandCode::StatusIout).await?;
+        Ok(StatusIout::from_raw(raw))
+    }
+
+    /// Write STATUS_IOUT to clear bits (0x7B).
+    pub async fn set_status_iout(
+        &mut self,
+        addr: u8,
+        status: StatusIout,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_cmd_byte(addr, CommandCode::StatusIout, status.bits())
+            .await
+    }
+
+    /// Read STATUS_INPUT (0x7C).
+    pub async fn get_status_input(
+        &mut self,
+        addr: u8,
+    ) -> Result<StatusInput, PmbusError<BUS::Error>> {
+        let raw = self
+            .read_status_byte(addr, CommandCode::StatusInput)
+            .await?;
+        Ok(StatusInput::from_raw(raw))
+    }
+
+    /// Write STATUS_INPUT to clear bits (0x7C).
+    pub async fn set_status_input(
+        &mut self,
+        addr: u8,
+        status: StatusInput,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_cmd_byte(addr, CommandCode::StatusInput, status.bits())
+            .await
+    }
+
+    /// Read STATUS_TEMPERATURE (0x7D).
+    pub async fn get_status_temperature(
+        &mut self,
+        addr: u8,
+    ) -> Result<StatusTemperature, PmbusError<BUS::Error>> {
+        let raw = self
+            .read_status_byte(addr, CommandCode::StatusTemperature)
+            .await?;
+        Ok(StatusTemperature::from_raw(raw))
+    }
+
+    /// Write STATUS_TEMPERATURE to clear bits (0x7D).
+    pub async fn set_status_temperature(
+        &mut self,
+        addr: u8,
+        status: StatusTemperature,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_cmd_byte(addr, CommandCode::StatusTemperature, status.bits())
+            .await
+    }
+
+    /// Read STATUS_CML (0x7E).
+    pub async fn get_status_cml(&mut self, addr: u8) -> Result<StatusCml, PmbusError<BUS::Error>> {
+        let raw = self.read_status_byte(addr, CommandCode::StatusCml).await?;
+        Ok(StatusCml::from_raw(raw))
+    }
+
+    /// Write STATUS_CML to clear bits (0x7E).
+    pub async fn set_status_cml(
+        &mut self,
+        addr: u8,
+        status: StatusCml,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_cmd_byte(addr, CommandCode::StatusCml, status.bits())
+            .await
+    }
+
+    /// Read STATUS_OTHER (0x7F).
+    pub async fn get_status_other(
+        &mut self,
+        addr: u8,
+    ) -> Result<StatusOther, PmbusError<BUS::Error>> {
+        let raw = self
+            .read_status_byte(addr, CommandCode::StatusOther)
+            .await?;
+        Ok(StatusOther::from_raw(raw))
+    }
+
+    /// Write STATUS_OTHER to clear bits (0x7F).
+    pub async fn set_status_other(
+        &mut self,
+        addr: u8,
+        status: StatusOther,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_cmd_byte(addr, CommandCode::StatusOther, status.bits())
+            .await
+    }
+
+    /// Read STATUS_MFR_SPECIFIC (0x80).
+    pub async fn get_status_mfr_specific(
+        &mut self,
+        addr: u8,
+    ) -> Result<u8, PmbusError<BUS::Error>> {
+        self.read_status_byte(addr, CommandCode::StatusMfrSpecific)
+            .await
+    }
+
+    /// Read STATUS_MFR_SPECIFIC (0x80) as a word.
+    ///
+    /// PMBus defines this register as a byte, but some controllers extend it
+    /// to 16 bits. The width is device-specific: check the datasheet, since
+    /// a word read of a byte register returns a garbage high byte (or NACKs).
+    pub async fn get_status_mfr_specific_word(
+        &mut self,
+        addr: u8,
+    ) -> Result<u16, PmbusError<BUS::Error>> {
+        self.read_status_word(addr, CommandCode::StatusMfrSpecific)
+            .await
+    }
+
+    /// Read STATUS_MFR_SPECIFIC (0x80) at the width the device reports.
+    ///
+    /// The first call per address QUERYs the register and remembers whether
+    /// its format is a 16-bit one (LINEAR11, ULINEAR16 or DIRECT). Any other
+    /// format, the usual bit-field answer included, is read as the standard
+    /// byte.
+    pub async fn get_status_mfr_specific_auto(
+        &mut self,
+        addr: u8,
+    ) -> Result<u16, PmbusError<BUS::Error>> {
+        let is_word = match self.mfr_status_word.get(&addr) {
+            Some(&is_word) => is_word,
+            None => {
+                let query = self
+                    .query_command(addr, CommandCode::StatusMfrSpecific)
+                    .await?;
+                let is_word = query.format.is_word().unwrap_or(false);
+                let _ = self.mfr_status_word.insert(addr, is_word);
+                is_word
+            }
+        };
+        if is_word {
+            self.get_status_mfr_specific_word(addr).await
+        } else {
+            Ok(self.get_status_mfr_specific(addr).await? as u16)
+        }
+    }
+
+    /// Read any status register and wrap it in the matching typed value.
+    ///
+    /// Returns [`PmbusError::NotStatusCommand`] for codes outside
+    /// [`STATUS_COMMANDS`].
+    pub async fn read_status(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+    ) -> Result<StatusValue, PmbusError<BUS::Error>> {
+        let raw = self.read_status_raw(addr, cmd).await?;
+        StatusValue::from_raw(cmd, raw).ok_or(PmbusError::NotStatusCommand)
+    }
+
+    /// Read any status register and test it against `mask`.
+    ///
+    /// For monitoring where the bit to watch comes from configuration.
+    /// Returns `true` if any bit of `mask` is set; STATUS_WORD is tested as
+    /// a 16-bit value, every other register as a byte (so mask bits above
+    /// bit 7 never match). Undefined bits are tested as read. Returns
+    /// [`PmbusError::NotStatusCommand`] for codes outside
+    /// [`STATUS_COMMANDS`].
+    pub async fn is_status_bit_set(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+        mask: u16,
+    ) -> Result<bool, PmbusError<BUS::Error>> {
+        Ok(self.read_status_raw(addr, cmd).await? & mask != 0)
+    }
+
+    /// Read a status register at its native width.
+    async fn read_status_raw(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+    ) -> Result<u16, PmbusError<BUS::Error>> {
+        if !STATUS_COMMANDS.contains(&cmd) {
+            return Err(PmbusError::NotStatusCommand);
+        }
+        if cmd == CommandCode::StatusWord {
+            self.read_status_word(addr, cmd).await
+        } else {
+            Ok(self.read_status_byte(addr, cmd).await? as u16)
+        }
+    }
+
+    /// Write STATUS_MFR_SPECIFIC to clear bits (0x80).
+    pub async fn set_status_mfr_specific(
+        &mut self,
+        addr: u8,
+        data: u8,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_cmd_byte(addr, CommandCode::StatusMfrSpecific, data)
+            .await
+    }
+
+    /// Read STATUS_FANS_1_2 (0x81).
+    pub async fn get_status_fans_12(
+        &mut self,
+        addr: u8,
+    ) -> Result<StatusFans12, PmbusError<BUS::Error>> {
+        let raw = self
+            .read_status_byte(addr, CommandCode::StatusFans12)
+            .await?;
+        Ok(StatusFans12::from_raw(raw))
+    }
+
+    /// Write STATUS_FANS_1_2 to clear bits (0x81).
+    pub async fn set_status_fans_12(
+        &mut self,
+        addr: u8,
+        status: StatusFans12,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_cmd_byte(addr, CommandCode::StatusFans12, status.bits())
+            .await
+    }
+
+    /// Read STATUS_FANS_3_4 (0x82).
+    pub async fn get_status_fans_34(
+        &mut self,
+        addr: u8,
+    ) -> Result<StatusFans34, PmbusError<BUS::Error>> {
+        let raw = self
+            .read_status_byte(addr, CommandCode::StatusFans34)
+            .await?;
+        Ok(StatusFans34::from_raw(raw))
+    }
+
+    /// Write STATUS_FANS_3_4 to clear bits (0x82).
+    pub async fn set_status_fans_34(
+        &mut self,
+        addr: u8,
+        status: StatusFans34,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_cmd_byte(addr, CommandCode::StatusFans34, status.bits())
+            .await
+    }
+
+    /// Read STATUS_WORD and every status register it flags.
+    ///
+    /// Only the registers whose summary bit is set are read, so devices that
+    /// don't implement e.g. the fan registers are never addressed for them.
+    /// When FANS is set, STATUS_FANS_3_4 is read alongside STATUS_FANS_1_2 if
+    /// the device has it, which the first such call per address asks QUERY;
+    /// a device that NACKs the QUERY gets STATUS_FANS_3_4 read only when
+    /// STATUS_FANS_1_2 is clear. Afterwards the configured [`AutoClear`]
+    /// policy is applied.
+    pub async fn read_all_status(
+        &mut self,
+        addr: u8,
+    ) -> Result<StatusSnapshot, PmbusError<BUS::Error>> {
+        let mut snap = StatusSnapshot::empty();
+        snap.word = self.get_status_word(addr).await?;
+        let word = snap.word;
+
+        if word.contains(StatusWord::VOUT) {
+            snap.vout = self.get_status_vout(addr).await?;
+        }
+        if word.contains(StatusWord::IOUT_POUT) {
+            snap.iout = self.get_status_iout(addr).await?;
+        }
+        if word.contains(StatusWord::INPUT) {
+            snap.input = self.get_status_input(addr).await?;
+        }
+        if word.contains(StatusWord::TEMPERATURE) {
+            snap.temperature = self.get_status_temperature(addr).await?;
+        }
+        if word.contains(StatusWord::CML) {
+            snap.cml = self.get_status_cml(addr).await?;
+        }
+        if word.contains(StatusWord::OTHER) {
+            snap.other = self.get_status_other(addr).await?;
+        }
+        if word.contains(StatusWord::MFR_SPECIFIC) {
+            snap.mfr_specific = self.get_status_mfr_specific(addr).await?;
+        }
+        if word.contains(StatusWord::FANS) {
+            snap.fans_12 = self.get_status_fans_12(addr).await?;
+            if snap.fans_12.is_empty() || self.has_fans_34(addr).await? {
+                snap.fans_34 = self.get_status_fans_34(addr).await?;
+            }
+        }
+
+        match self.auto_clear {
+            AutoClear::Off => {}
+            AutoClear::WriteBack => self.write_back_status(addr, &snap).await?,
+            AutoClear::ClearFaults => self.clear_faults(addr).await?,
+        }
+
+        Ok(snap)
+    }
+
+    /// Whether `addr` implements STATUS_FANS_3_4, from QUERY on first use.
+    async fn has_fans_34(&mut self, addr: u8) -> Result<bool, PmbusError<BUS::Error>> {
+        if let Some(&present) = self.fans_34.get(&addr) {
+            return Ok(present);
+        }
+        let present = match self.query_command(addr, CommandCode::StatusFans34).await {
+            Ok(query) => query.supported,
+            Err(PmbusError::Bus(e)) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => false,
+            Err(e) => return Err(e),
+        };
+        let _ = self.fans_34.insert(addr, present);
+        Ok(present)
+    }
+
+    /// Write-1-to-clear every non-empty register of a snapshot.
+    async fn write_back_status(
+        &mut self,
+        addr: u8,
+        snap: &StatusSnapshot,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        if !snap.vout.is_empty() {
+            self.set_status_vout(addr, snap.vout).await?;
+        }
+        if !snap.iout.is_empty() {
+            self.set_status_iout(addr, snap.iout).await?;
+        }
+        if !snap.input.is_empty() {
+            self.set_status_input(addr, snap.input).await?;
+        }
+        if !snap.temperature.is_empty() {
+            self.set_status_temperature(addr, snap.temperature).await?;
+        }
+        if !snap.cml.is_empty() {
+            self.set_status_cml(addr, snap.cml).await?;
+        }
+        if !snap.other.is_empty() {
+            self.set_status_other(addr, snap.other).await?;
+        }
+        if snap.mfr_specific != 0 {
+            self.set_status_mfr_specific(addr, snap.mfr_specific)
+                .await?;
+        }
+        if !snap.fans_12.is_empty() {
+            self.set_status_fans_12(addr, snap.fans_12).await?;
+        }
+        if !snap.fans_34.is_empty() {
+            self.set_status_fans_34(addr, snap.fans_34).await?;
+        }
+        if !snap.word.is_empty() {
+            self.set_status_word(addr, snap.word).await?;
+        }
+        Ok(())
+    }
+
+    // =======================================================================
+    // Special commands — manual implementations
+    // =======================================================================
+
+    /// Read VOUT_MODE (0x20) and parse into `VoutMode`.
+    pub async fn get_vout_mode(&mut self, addr: u8) -> Result<VoutMode, PmbusError<BUS::Error>> {
+        let raw = self.read_cmd_byte(addr, CommandCode::VoutMode).await?;
+        Ok(VoutMode::from_raw(raw))
+    }
+
+    /// Write VOUT_MODE (0x20) from a `VoutMode` value.
+    pub async fn set_vout_mode(
+        &mut self,
+        addr: u8,
+        mode: VoutMode,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.vout_modes.remove(&addr);
+        self.write_cmd_byte(addr, CommandCode::VoutMode, mode.to_raw())
+            .await
+    }
+
+    /// Read VOUT_MODE (0x20) and remember it for
+    /// [`read_vout_f32_cached`](Self::read_vout_f32_cached).
+    ///
+    /// The entry belongs to the device's current page: a multi-rail device
+    /// can use a different exponent on each PAGE, so it is dropped whenever
+    /// the cached page changes or is forgotten, and by bus recovery.
+    /// [`set_vout_mode`](Self::set_vout_mode) drops it too; PAGE or
+    /// VOUT_MODE writes through the `raw_*` methods don't.
+    pub async fn cache_vout_mode(&mut self, addr: u8) -> Result<VoutMode, PmbusError<BUS::Error>> {
+        let mode = self.get_vout_mode(addr).await?;
+        let _ = self.vout_modes.insert(addr, mode);
+        Ok(mode)
+    }
+
+    /// The VOUT_MODE cached for `addr`, if any.
+    pub fn cached_vout_mode(&self, addr: u8) -> Option<VoutMode> {
+        self.vout_modes.get(&addr).copied()
+    }
+
+    /// Forget the cached VOUT_MODE of `addr`.
+    pub fn invalidate_vout_mode_cache(&mut self, addr: u8) {
+        self.vout_modes.remove(&addr);
+    }
+
+    /// Like [`read_vout_f32`](Self::read_vout_f32), but decodes with the
+    /// cached VOUT_MODE, so only READ_VOUT goes over the bus.
+    ///
+    /// VOUT_MODE is read and cached first if `addr` has no entry yet.
+    pub async fn read_vout_f32_cached(&mut self, addr: u8) -> Result<f32, PmbusError<BUS::Error>> {
+        let exponent = self.cached_vout_exponent(addr).await?;
+        self.read_vout_with_exponent(addr, exponent).await
+    }
+
+    /// The ULINEAR16 exponent from the cached VOUT_MODE, reading and caching
+    /// it first if `addr` has no entry yet.
+    async fn cached_vout_exponent(&mut self, addr: u8) -> Result<i8, PmbusError<BUS::Error>> {
+        let mode = match self.cached_vout_mode(addr) {
+            Some(mode) => mode,
+            None => self.cache_vout_mode(addr).await?,
+        };
+        exponent_of(mode)
+    }
+
+    /// Read COEFFICIENTS (0x30) using block read/write process call.
+    ///
+    /// Returns the DIRECT coefficients of `cmd` in the given `direction`;
+    /// a device may scale values it reports differently from values it
+    /// accepts. The query bytes are built from `cmd` and `direction`, so
+    /// e.g. `get_coefficients(addr, CommandCode::ReadIout,
+    /// CoeffDirection::Read)` is all a DIRECT-format driver needs.
+    pub async fn get_coefficients(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+        direction: CoeffDirection,
+    ) -> Result<DirectCoefficients, PmbusError<BUS::Error>> {
+        let query = [cmd.code(), direction as u8];
+        let resp = self
+            .block_process_call_cmd(addr, CommandCode::Coefficients, &query)
+            .await?;
+        // Response: [byte_count, m_low, m_high, b_low, b_high, r]
+        DirectCoefficients::from_coefficients_response(block_data(&resp, 5)?)
+            .ok_or(PmbusError::InvalidResponseLength)
+    }
+
+    /// Same as [`get_coefficients`](Self::get_coefficients).
+    pub async fn read_coefficients_for(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+        direction: CoeffDirection,
+    ) -> Result<DirectCoefficients, PmbusError<BUS::Error>> {
+        self.get_coefficients(addr, cmd, direction).await
+    }
+
+    /// Execute QUERY command (0x1A) — asks the device about a command's support.
+    pub async fn query(&mut self, addr: u8, command: u8) -> Result<u8, PmbusError<BUS::Error>> {
+        self.process_call_cmd(addr, CommandCode::Query, command as u16)
+            .await
+            .map(|w| w as u8)
+    }
+
+    /// QUERY (0x1A) a standard command and decode the response.
+    pub async fn query_command(
+        &mut self,
+        addr: u8,
+        cmd: CommandCode,
+    ) -> Result<QueryResponse, PmbusError<BUS::Error>> {
+        Ok(QueryResponse::from_raw(self.query(addr, cmd.code()).await?))
+    }
+
+    /// QUERY each command in `expectations` and check it is supported with
+    /// the expected data format.
+    ///
+    /// Meant for driver start-up, to catch the wrong part at an address
+    /// before it returns misleading telemetry. Stops at the first mismatch
+    /// with [`PmbusError::CommandUnsupported`] or
+    /// [`PmbusError::UnexpectedFormat`].
+    pub async fn assert_commands(
+        &mut self,
+        addr: u8,
+        expectations: &[(CommandCode, DataFormat)],
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        for &(cmd, format) in expectations {
+            let response = self.query_command(addr, cmd).await?;
+            if !response.supported {
+                return Err(PmbusError::CommandUnsupported { cmd });
+            }
+            if response.format != format {
+                return Err(PmbusError::UnexpectedFormat { cmd });
+            }
+        }
+        Ok(())
+    }
+
+    /// Read SMBALERT_MASK (0x1B) using process call.
+    pub async fn get_smbalert_mask(
+        &mut self,
+        addr: u8,
+        status_register: u8,
+    ) -> Result<u8, PmbusError<BUS::Error>> {
+        self.process_call_cmd(addr, CommandCode::SmbalertMask, status_register as u16)
+            .await
+            .map(|w| w as u8)
+    }
+
+    /// Write SMBALERT_MASK (0x1B).
+    pub async fn set_smbalert_mask(
+        &mut self,
+        addr: u8,
+        data: u16,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_cmd_word(addr, CommandCode::SmbalertMask, data)
+            .await
+    }
+
+    /// Read the SMBALERT_MASK of `reg` as a typed value.
+    ///
+    /// Set flags in the result are the conditions that do *not* assert
+    /// SMBALERT#.
+    pub async fn get_smbalert_mask_typed(
+        &mut self,
+        addr: u8,
+        reg: StatusRegister,
+    ) -> Result<StatusValue, PmbusError<BUS::Error>> {
+        let cmd = reg.command();
+        let mask = self.get_smbalert_mask(addr, cmd.code()).await?;
+        StatusValue::from_raw(cmd, mask as u16).ok_or(PmbusError::NotStatusCommand)
+    }
+
+    /// Write the SMBALERT_MASK of the register `mask` belongs to.
+    ///
+    /// Returns [`PmbusError::NotStatusCommand`] for STATUS_BYTE and
+    /// STATUS_WORD values, which can't be masked.
+    pub async fn set_smbalert_mask_typed(
+        &mut self,
+        addr: u8,
+        mask: StatusValue,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        let reg =
+            StatusRegister::from_command(mask.command()).ok_or(PmbusError::NotStatusCommand)?;
+        let data = u16::from_le_bytes([reg.command().code(), mask.bits() as u8]);
+        self.set_smbalert_mask(addr, data).await
+    }
+
+    /// Read PAGE_PLUS_READ (0x06) — reads a byte from a specific page in one transaction.
+    pub async fn page_plus_read(
+        &mut self,
+        addr: u8,
+        page: u8,
+        command: u8,
+    ) -> Result<Vec<u8, 33>, PmbusError<BUS::Error>> {
+        self.block_process_call_cmd(addr, CommandCode::PagePlusRead, &[page, command])
+            .await
+    }
+
+    /// Write PAGE_PLUS_WRITE (0x05) — writes data to a specific page in one transaction.
+    pub async fn page_plus_write(
+        &mut self,
+        addr: u8,
+        data: &[u8],
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.block_write_cmd(addr, CommandCode::PagePlusWrite, data)
+            .await
+    }
+
+    /// Read KWH_IN (0x83) — 4-byte (32-bit) read via I2C write_read, in the
+    /// [configured byte order](Self::set_kwh_byte_order).
+    #[cfg(feature = "energy")]
+    pub async fn read_kwh_in(&mut self, addr: u8) -> Result<u32, PmbusError<BUS::Error>> {
+        let mut buf = [0u8; 4];
+        self.write_read_cmd(addr, &[CommandCode::ReadKwhIn.code()], &mut buf)
+            .await?;
+        Ok(self.kwh_byte_order.u32_from_bytes(buf))
+    }
+
+    /// Read KWH_OUT (0x84) — 4-byte (32-bit) read via I2C write_read, in the
+    /// [configured byte order](Self::set_kwh_byte_order).
+    #[cfg(feature = "energy")]
+    pub async fn read_kwh_out(&mut self, addr: u8) -> Result<u32, PmbusError<BUS::Error>> {
+        let mut buf = [0u8; 4];
+        self.write_read_cmd(addr, &[CommandCode::ReadKwhOut.code()], &mut buf)
+            .await?;
+        Ok(self.kwh_byte_order.u32_from_bytes(buf))
+    }
+
+    // =======================================================================
+    // Raw methods for manufacturer-specific codes
+    // =======================================================================
+
+    /// Read a byte from any command code.
+    pub async fn raw_read_byte(
+        &mut self,
+        addr: u8,
+        code: u8,
+    ) -> Result<u8, PmbusError<BUS::Error>> {
+        self.execute_byte(Transaction::ReadByte { addr, code })
+            .await
+    }
+
+    /// Write a byte to any command code.
+    pub async fn raw_write_byte(
+        &mut self,
+        addr: u8,
+        code: u8,
+        data: u8,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.execute(Transaction::WriteByte { addr, code, data })
+            .await?;
+        Ok(())
+    }
+
+    /// Read a word from any command code.
+    pub async fn raw_read_word(
+        &mut self,
+        addr: u8,
+        code: u8,
+    ) -> Result<u16, PmbusError<BUS::Error>> {
+        self.execute_word(Transaction::ReadWord { addr, code })
+            .await
+    }
+
+    /// Read two bytes starting at `code` in one transaction.
+    ///
+    /// For devices that pack adjacent registers (e.g. STATUS_CML and
+    /// STATUS_OTHER) behind a single 2-byte read. The bytes are returned in
+    /// wire order: `[code, code + 1]`. Whether a device supports this is
+    /// vendor-specific.
+    pub async fn read_word_le(
+        &mut self,
+        addr: u8,
+        code: u8,
+    ) -> Result<[u8; 2], PmbusError<BUS::Error>> {
+        Ok(self.raw_read_word(addr, code).await?.to_le_bytes())
+    }
+
+    /// Write a word to any command code.
+    pub async fn raw_write_word(
+        &mut self,
+        addr: u8,
+        code: u8,
+        data: u16,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.execute(Transaction::WriteWord { addr, code, data })
+            .await?;
+        Ok(())
+    }
+
+    /// Block read from any command code.
+    pub async fn raw_block_read(
+        &mut self,
+        addr: u8,
+        code: u8,
+    ) -> Result<Vec<u8, 33>, PmbusError<BUS::Error>> {
+        self.execute_block(Transaction::BlockRead { addr, code })
+            .await
+    }
+
+    /// Block write to any command code.
+    pub async fn raw_block_write(
+        &mut self,
+        addr: u8,
+        code: u8,
+        data: &[u8],
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.execute(Transaction::BlockWrite { addr, code, data })
+            .await?;
+        Ok(())
+    }
+
+    /// Write `write`, then fill `read` after a repeated start, in one
+    /// transaction.
+    ///
+    /// For vendor protocols that aren't byte, word or block shaped. `write`
+    /// starts with the command code and must not be empty; an empty `read`
+    /// makes this a plain write. PEC, if enabled, is appended to the write
+    /// and checked after `read` as for any other command.
+    pub async fn raw_write_read(
+        &mut self,
+        addr: u8,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_read_cmd(addr, write, read).await
+    }
+
+    // =======================================================================
+    // Extended command protocol
+    // =======================================================================
+
+    /// Extended read byte — sends [prefix, ext_cmd] and reads 1 byte.
+    pub async fn extended_read_byte(
+        &mut self,
+        addr: u8,
+        prefix: u8,
+        ext_cmd: u8,
+    ) -> Result<u8, PmbusError<BUS::Error>> {
+        let mut buf = [0u8; 1];
+        self.write_read_cmd(addr, &[prefix, ext_cmd], &mut buf)
+            .await?;
+        Ok(buf[0])
+    }
+
+    /// Extended write byte — sends [prefix, ext_cmd, data].
+    pub async fn extended_write_byte(
+        &mut self,
+        addr: u8,
+        prefix: u8,
+        ext_cmd: u8,
+        data: u8,
+    ) -> Result<(), PmbusError<BUS::Error>> {
+        self.write_read_cmd(addr, &[prefix, ext_cmd, data], &mut [])
+            .await
+    }
+
+    /// Extended read word — sends [prefix, ext_cmd] and reads 2 bytes (LE).
+    pub async fn extended_read_word(
         &mut self,
         addr: u8,
         prefix: u8,
         ext_cmd: u8,
-    ) -> Result<u16, BUS::Error> {
+    ) -> Result<u16, PmbusError<BUS::Error>> {
         let mut buf = [0u8; 2];
-        self.smbus
-            .write_read(addr, &[prefix, ext_cmd], &mut buf)
+        self.write_read_cmd(addr, &[prefix, ext_cmd], &mut buf)
             .await?;
         Ok(u16::from_le_bytes(buf))
     }
@@ -870,10 +3916,1489 @@ impl<BUS: I2c + 'static> PmbusAdaptor<BUS> {
         prefix: u8,
         ext_cmd: u8,
         data: u16,
-    ) -> Result<(), BUS::Error> {
+    ) -> Result<(), PmbusError<BUS::Error>> {
         let bytes = data.to_le_bytes();
-        self.smbus
-            .write(addr, &[prefix, ext_cmd, bytes[0], bytes[1]])
+        self.write_read_cmd(addr, &[prefix, ext_cmd, bytes[0], bytes[1]], &mut [])
             .await
     }
 }
+
+/// Encode the six timing registers of `limits` as LINEAR11, in write order.
+#[cfg(feature = "limits")]
+fn encode_timing_limits<E>(
+    limits: &TimingLimits,
+) -> Result<[(CommandCode, u16); 6], PmbusError<E>> {
+    let values = [
+        (CommandCode::TonDelay, limits.ton_delay),
+        (CommandCode::TonRise, limits.ton_rise),
+        (CommandCode::TonMaxFaultLimit, limits.ton_max_fault_limit),
+        (CommandCode::ToffDelay, limits.toff_delay),
+        (CommandCode::ToffFall, limits.toff_fall),
+        (CommandCode::ToffMaxWarnLimit, limits.toff_max_warn_limit),
+    ];
+    let mut raw = [(CommandCode::TonDelay, 0u16); 6];
+    for (slot, (cmd, ms)) in raw.iter_mut().zip(values) {
+        let value = Linear11::from_f32(ms).ok_or(PmbusError::EncodingError)?;
+        *slot = (cmd, value.raw());
+    }
+    Ok(raw)
+}
+
+/// The data bytes of a block read, after checking at least `min` arrived.
+fn block_data<E>(block: &[u8], min: usize) -> Result<&[u8], PmbusError<E>> {
+    let data = block.get(1..).unwrap_or(&[]);
+    if data.len() < min {
+        return Err(PmbusError::ShortRead {
+            expected: min,
+            got: data.len(),
+        });
+    }
+    Ok(data)
+}
+
+/// The SMBus count byte for a block of `data`.
+fn block_count<E>(data: &[u8]) -> Result<u8, PmbusError<E>> {
+    u8::try_from(data.len()).map_err(|_| PmbusError::BlockTooLong)
+}
+
+/// Data format of a limit command.
+#[cfg(feature = "limits")]
+enum LimitFormat {
+    Vout,
+    Linear11,
+}
+
+/// The format `cmd` is encoded in, if it is a limit or threshold.
+#[cfg(feature = "limits")]
+fn limit_format(cmd: CommandCode) -> Option<LimitFormat> {
+    use CommandCode::*;
+    match cmd {
+        VoutOvFaultLimit | VoutOvWarnLimit | VoutUvWarnLimit | VoutUvFaultLimit
+        | IoutOcLvFaultLimit | PowerGoodOn | PowerGoodOff => Some(LimitFormat::Vout),
+        IoutOcFaultLimit | IoutOcWarnLimit | IoutUcFaultLimit | OtFaultLimit | OtWarnLimit
+        | UtWarnLimit | UtFaultLimit | VinOvFaultLimit | VinOvWarnLimit | VinUvWarnLimit
+        | VinUvFaultLimit | IinOcFaultLimit | IinOcWarnLimit | TonMaxFaultLimit
+        | ToffMaxWarnLimit | PoutOpFaultLimit | PoutOpWarnLimit | PinOpWarnLimit => {
+            Some(LimitFormat::Linear11)
+        }
+        _ => None,
+    }
+}
+
+/// Encode an absolute output voltage as ULINEAR16.
+#[cfg(feature = "limits")]
+fn encode_vout<E>(volts: f32, exponent: i8) -> Result<u16, PmbusError<E>> {
+    ULinear16::from_f32(volts, exponent)
+        .map(ULinear16::raw)
+        .ok_or(PmbusError::EncodingError)
+}
+
+/// Encode a limit in its wire format; `exponent` is only used for
+/// output-voltage limits.
+#[cfg(feature = "limits")]
+fn encode_limit<E>(cmd: CommandCode, value: f32, exponent: i8) -> Result<u16, PmbusError<E>> {
+    match limit_format(cmd).ok_or(PmbusError::NotALimit)? {
+        LimitFormat::Vout => encode_vout(value, exponent),
+        LimitFormat::Linear11 => Linear11::from_f32(value)
+            .map(Linear11::raw)
+            .ok_or(PmbusError::EncodingError),
+    }
+}
+
+/// The READ_TEMPERATURE_n commands, in sensor order.
+const TEMPERATURE_SENSORS: [CommandCode; 3] = [
+    CommandCode::ReadTemperature1,
+    CommandCode::ReadTemperature2,
+    CommandCode::ReadTemperature3,
+];
+
+/// Decode a ULINEAR16 output voltage, rejecting an exponent VOUT_MODE
+/// can't produce instead of returning NaN.
+fn decode_vout<E>(raw: u16, exponent: i8) -> Result<f32, PmbusError<E>> {
+    ULinear16::from_raw(raw)
+        .checked_to_f32(exponent)
+        .ok_or(PmbusError::UnsupportedVoutMode)
+}
+
+/// The ULINEAR16 exponent of `mode`, if it uses that format.
+fn exponent_of<E>(mode: VoutMode) -> Result<i8, PmbusError<E>> {
+    match mode.mode {
+        VoutModeType::ULinear16 { exponent } => Ok(exponent),
+        _ => Err(PmbusError::UnsupportedVoutMode),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use mock::{MockBus, adaptor, block_on};
+
+    #[cfg(feature = "record")]
+    #[test]
+    fn record_mode_captures_writes_without_bus() {
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        pmbus.set_recording(true);
+        block_on(async {
+            pmbus.set_page(0x40, 1).await.unwrap();
+            pmbus.set_vout_command(0x40, 0x0266).await.unwrap();
+            pmbus.store_user_all(0x40).await.unwrap();
+            pmbus.raw_block_write(0x40, 0x99, b"AB").await.unwrap();
+        });
+        assert!(bus.writes().is_empty());
+
+        let log = pmbus.take_log();
+        assert_eq!(log.len(), 4);
+        assert_eq!(log[0].command, CommandCode::Page.code());
+        assert_eq!(log[0].data.as_slice(), &[1]);
+        assert_eq!(log[1].data.as_slice(), &[0x66, 0x02]);
+        assert!(log[2].data.is_empty());
+        assert_eq!(log[3].data.as_slice(), &[2, b'A', b'B']);
+        assert!(pmbus.take_log().is_empty());
+    }
+
+    #[cfg(feature = "record")]
+    #[test]
+    fn record_mode_rejects_empty_write_read() {
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        pmbus.set_recording(true);
+        let tx = Transaction::WriteRead {
+            addr: 0x40,
+            write: &[],
+            read: &mut [],
+        };
+        let err = block_on(pmbus.execute(tx)).unwrap_err();
+        assert!(matches!(err, PmbusError::EncodingError));
+        assert!(pmbus.take_log().is_empty());
+    }
+
+    #[cfg(feature = "record")]
+    #[test]
+    fn record_mode_leaves_page_cache_untouched() {
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        block_on(async {
+            pmbus.set_page(0x40, 0).await.unwrap();
+            pmbus.set_recording(true);
+            pmbus.set_page(0x40, 1).await.unwrap();
+            pmbus.select_page(0x40, 1).await.unwrap();
+            pmbus.set_page(0x41, 2).await.unwrap();
+            assert_eq!(pmbus.take_log().len(), 2);
+            pmbus.set_recording(false);
+
+            assert_eq!(pmbus.cached_page(0x40), Some(0));
+            assert_eq!(pmbus.cached_page(0x41), None);
+            pmbus.select_page(0x40, 1).await.unwrap();
+        });
+        let writes: std::vec::Vec<_> = bus.writes().into_iter().map(|(_, w)| w).collect();
+        assert_eq!(writes, [std::vec![0x00, 0], std::vec![0x00, 1]]);
+    }
+
+    #[test]
+    fn modify_preserves_other_bits() {
+        let bus = MockBus::with_reads(&[0x17, 0x1F, 0x34, 0x12]);
+        let mut pmbus = adaptor(&bus);
+        block_on(async {
+            let cfg = pmbus
+                .modify_byte(0x40, CommandCode::OnOffConfig, |b| b | 0x08)
+                .await
+                .unwrap();
+            assert_eq!(cfg, 0x1F);
+            // Already set: no write.
+            pmbus
+                .modify_byte(0x40, CommandCode::OnOffConfig, |b| b | 0x08)
+                .await
+                .unwrap();
+            let word = pmbus
+                .modify_word(0x40, CommandCode::VoutCommand, |w| w & 0xFF00)
+                .await
+                .unwrap();
+            assert_eq!(word, 0x1200);
+        });
+        let writes = bus.writes();
+        assert_eq!(writes.len(), 5);
+        assert_eq!(writes[1].1, [0x02, 0x1F]);
+        assert_eq!(writes[4].1, [0x21, 0x00, 0x12]);
+    }
+
+    #[test]
+    fn wait_if_busy_polls_status_byte() {
+        let bus = MockBus::with_reads(&[0x80, 0xC0, 0x40]);
+        let mut pmbus = adaptor(&bus);
+        let mut delay = mock::CountingDelay::default();
+        block_on(async {
+            pmbus.wait_if_busy(0x40, 10, &mut delay).await.unwrap();
+            assert_eq!(delay.ms, 2);
+            // Exhausted script reads 0xFF: BUSY never clears.
+            let err = pmbus.wait_if_busy(0x40, 3, &mut delay).await.unwrap_err();
+            assert!(matches!(err, PmbusError::DeviceBusy));
+        });
+        assert_eq!(bus.writes().len(), 7);
+    }
+
+    #[test]
+    fn factory_reset_waits_then_verifies() {
+        // BUSY once, then idle; VOUT_COMMAND reads back 0x0200.
+        let reads = [0x80, 0x00, 0x00, 0x02];
+        let verify = Some((CommandCode::VoutCommand, 0x0200));
+        let bus = MockBus::with_reads(&reads);
+        let mut pmbus = adaptor(&bus);
+        let mut delay = mock::CountingDelay::default();
+        block_on(pmbus.factory_reset(0x40, verify, 10, &mut delay)).unwrap();
+        assert_eq!(bus.writes()[0].1, [CommandCode::RestoreDefaultAll.code()]);
+        assert_eq!(delay.ms, 1);
+
+        let bus = MockBus::with_reads(&reads);
+        let mut pmbus = adaptor(&bus);
+        let verify = Some((CommandCode::VoutCommand, 0x0266));
+        let err = block_on(pmbus.factory_reset(0x40, verify, 10, &mut delay)).unwrap_err();
+        assert!(matches!(err, PmbusError::ResetFailed));
+    }
+
+    #[test]
+    fn restores_forget_cached_page_and_vout_mode() {
+        let bus = MockBus::with_reads(&[0x17, 0x00, 0x17]);
+        let mut pmbus = adaptor(&bus);
+        let mut delay = mock::CountingDelay::default();
+        block_on(async {
+            pmbus.set_page(0x40, 1).await.unwrap();
+            pmbus.cache_vout_mode(0x40).await.unwrap();
+            pmbus.restore_user_all(0x40).await.unwrap();
+            assert_eq!(pmbus.cached_page(0x40), None);
+            assert_eq!(pmbus.cached_vout_mode(0x40), None);
+
+            pmbus.set_page(0x40, 1).await.unwrap();
+            pmbus.cache_vout_mode(0x40).await.unwrap();
+            pmbus
+                .factory_reset(0x40, None, 10, &mut delay)
+                .await
+                .unwrap();
+            assert_eq!(pmbus.cached_page(0x40), None);
+            assert_eq!(pmbus.cached_vout_mode(0x40), None);
+
+            // A failed restore leaves the caches alone.
+            pmbus.set_page(0x40, 1).await.unwrap();
+            pmbus.cache_vout_mode(0x40).await.unwrap();
+            bus.nack_code(CommandCode::RestoreUserAll.code());
+            assert!(pmbus.restore_user_all(0x40).await.is_err());
+            assert_eq!(pmbus.cached_page(0x40), Some(1));
+        });
+    }
+
+    #[test]
+    fn clear_faults_on_each_page() {
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        let results = block_on(pmbus.clear_faults_all_pages(0x40, &[0, 1]));
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|(_, r)| r.is_ok()));
+        let writes: std::vec::Vec<_> = bus.writes().into_iter().map(|(_, w)| w).collect();
+        assert_eq!(
+            writes,
+            [
+                std::vec![0x00, 0],
+                std::vec![0x03],
+                std::vec![0x00, 1],
+                std::vec![0x03]
+            ]
+        );
+
+        block_on(pmbus.clear_faults_page_all(0x40)).unwrap();
+        let writes = bus.writes();
+        assert_eq!(writes[4].1, [0x00, PAGE_ALL]);
+        assert_eq!(writes[5].1, [0x03]);
+        assert_eq!(writes[6].1, [0x00, 1]);
+        assert_eq!(pmbus.cached_page(0x40), Some(1));
+
+        // An unknown page is read back first.
+        let bus = MockBus::with_reads(&[2]);
+        let mut pmbus = adaptor(&bus);
+        block_on(pmbus.clear_faults_page_all(0x40)).unwrap();
+        let writes: std::vec::Vec<_> = bus.writes().into_iter().map(|(_, w)| w).collect();
+        assert_eq!(
+            writes,
+            [
+                std::vec![0x00],
+                std::vec![0x00, PAGE_ALL],
+                std::vec![0x03],
+                std::vec![0x00, 2]
+            ]
+        );
+    }
+
+    #[test]
+    fn enumerate_pages_keeps_pages_that_stick() {
+        // Original page 1; pages 0 and 1 stick, page 2 reads back as 1.
+        let bus = MockBus::with_reads(&[1, 0, 1, 1]);
+        let mut pmbus = adaptor(&bus);
+        let pages = block_on(pmbus.enumerate_pages(0x40, 3)).unwrap();
+        assert_eq!(pages.as_slice(), &[0, 1]);
+        assert_eq!(bus.writes().last().unwrap().1, [0x00, 1]);
+        assert_eq!(pmbus.cached_page(0x40), Some(1));
+    }
+
+    #[test]
+    fn enumerate_pages_restores_page_on_bus_error() {
+        // Original page 1; page 0 sticks, then the PAGE write for page 1 fails.
+        let bus = MockBus::with_reads(&[1, 0]);
+        bus.fail_transaction(3);
+        let mut pmbus = adaptor(&bus);
+        let err = block_on(pmbus.enumerate_pages(0x40, 3)).unwrap_err();
+        assert!(matches!(err, PmbusError::Bus(ErrorKind::Bus)));
+        assert_eq!(bus.writes().last().unwrap().1, [0x00, 1]);
+        assert_eq!(pmbus.cached_page(0x40), Some(1));
+    }
+
+    #[cfg(feature = "energy")]
+    #[test]
+    fn kwh_byte_order() {
+        let bus = MockBus::with_reads(&[0x01, 0x02, 0x03, 0x04, 0x01, 0x02, 0x03, 0x04]);
+        let mut pmbus = adaptor(&bus);
+        block_on(async {
+            assert_eq!(pmbus.read_kwh_in(0x40).await.unwrap(), 0x0403_0201);
+            pmbus.set_kwh_byte_order(ByteOrder::Big);
+            assert_eq!(pmbus.read_kwh_out(0x40).await.unwrap(), 0x0102_0304);
+        });
+    }
+
+    #[cfg(feature = "energy")]
+    #[test]
+    fn energy_reading_from_block() {
+        let bus = MockBus::with_reads(&[6, 0x10, 0x00, 0x01, 0x08, 0x00, 0x00]);
+        let mut pmbus = adaptor(&bus);
+        let reading = block_on(pmbus.read_ein_parsed(0x40)).unwrap();
+        assert_eq!(reading.total(), 0x8010);
+        assert_eq!(reading.sample_count, 8);
+
+        let bus = MockBus::with_reads(&[5, 0, 0, 0, 0, 0]);
+        let mut pmbus = adaptor(&bus);
+        let err = block_on(pmbus.read_eout_parsed(0x40)).unwrap_err();
+        assert!(matches!(err, PmbusError::InvalidResponseLength));
+    }
+
+    #[test]
+    fn telemetry_retries_on_cml_comm_fault() {
+        // READ_VIN, STATUS_CML=PEC_FAILED, READ_VIN again, STATUS_CML clear.
+        let bus = MockBus::with_reads(&[0x11, 0x22, 0x20, 0x33, 0x44, 0x00]);
+        let mut pmbus = adaptor(&bus);
+        pmbus.set_cml_retries(1);
+        block_on(async {
+            assert_eq!(pmbus.read_vin(0x40).await.unwrap(), 0x4433);
+            // Script exhausted: every STATUS_CML read is 0xFF.
+            let err = pmbus.read_vin(0x40).await.unwrap_err();
+            assert!(matches!(err, PmbusError::CommFault));
+        });
+        let writes = bus.writes();
+        assert_eq!(writes[2].1, [0x7E, 0x20]);
+        assert_eq!(writes.len(), 11);
+    }
+
+    #[test]
+    fn verify_bus_speed_against_capability() {
+        // 400 kHz device.
+        let bus = MockBus::with_reads(&[0xB0, 0xB0]);
+        let mut pmbus = adaptor(&bus);
+        block_on(async {
+            pmbus.verify_bus_speed(0x40, 400).await.unwrap();
+            let err = pmbus.verify_bus_speed(0x40, 1000).await.unwrap_err();
+            assert!(matches!(
+                err,
+                PmbusError::BusSpeedTooHigh {
+                    configured_khz: 1000,
+                    max_khz: 400
+                }
+            ));
+        });
+    }
+
+    #[test]
+    fn read_all_vout_per_page_exponent() {
+        // Page 0: exponent -9, 1.0 V commanded, 0.998 V measured.
+        // Page 1: exponent -12, 3.3 V commanded and measured.
+        let bus = MockBus::with_reads(&[
+            0x17, 0x00, 0x02, 0xFF, 0x01, //
+            0x14, 0xCD, 0x34, 0xCD, 0x34,
+        ]);
+        let mut pmbus = adaptor(&bus);
+        let rails = block_on(pmbus.read_all_vout(0x40, &[0, 1])).unwrap();
+        assert_eq!(rails.len(), 2);
+        assert_eq!(rails[0].0, 0);
+        assert_eq!(rails[0].1.commanded, 1.0);
+        assert!((rails[0].1.measured - 0.998).abs() < 0.002);
+        assert_eq!(rails[1].0, 1);
+        assert!((rails[1].1.commanded - 3.3).abs() < 0.001);
+        assert_eq!(bus.writes()[4].1, [0x00, 1]);
+    }
+
+    #[test]
+    fn raw_write_read_single_transaction() {
+        let bus = MockBus::with_reads(&[0xAA, 0xBB, 0xCC]);
+        let mut pmbus = adaptor(&bus);
+        let mut buf = [0u8; 3];
+        block_on(async {
+            pmbus
+                .raw_write_read(0x40, &[0xD0, 1, 2], &mut buf)
+                .await
+                .unwrap();
+            assert!(pmbus.raw_write_read(0x40, &[], &mut buf).await.is_err());
+        });
+        assert_eq!(buf, [0xAA, 0xBB, 0xCC]);
+        assert_eq!(bus.writes(), [(0x40, std::vec![0xD0, 1, 2])]);
+    }
+
+    #[test]
+    fn all_ones_status_rejected_when_enabled() {
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        block_on(async {
+            assert!(pmbus.get_status_word(0x40).await.unwrap().is_all_ones());
+            pmbus.set_reject_all_ones_status(true);
+            let err = pmbus.get_status_word(0x40).await.unwrap_err();
+            assert!(matches!(err, PmbusError::NoValidResponse));
+            let err = pmbus.get_status_vout(0x40).await.unwrap_err();
+            assert!(matches!(err, PmbusError::NoValidResponse));
+        });
+    }
+
+    #[cfg(feature = "limits")]
+    #[test]
+    fn configure_rail_sequencing() {
+        // WRITE_PROTECT 0x80, VOUT_MODE exponent -9.
+        let bus = MockBus::with_reads(&[0x80, 0x17]);
+        let mut pmbus = adaptor(&bus);
+        let config = RailConfig {
+            vout_command: 1.0,
+            vout_max: Some(1.2),
+            limits: &[
+                (CommandCode::VoutOvFaultLimit, 1.1),
+                (CommandCode::OtFaultLimit, 100.0),
+            ],
+            fault_responses: &[(CommandCode::VoutOvFaultResponse, 0x80)],
+            on_off_config: Some(0x16),
+            store: true,
+            ..Default::default()
+        };
+        block_on(pmbus.configure_rail(0x40, 1, &config)).unwrap();
+        let codes: std::vec::Vec<u8> = bus.writes().iter().map(|(_, w)| w[0]).collect();
+        assert_eq!(
+            codes,
+            [
+                0x10, 0x10, 0x00, 0x20, 0x24, 0x41, 0x40, 0x4F, 0x21, 0x02, 0x15, 0x10
+            ]
+        );
+        let writes = bus.writes();
+        assert_eq!(writes[1].1, [0x10, 0x00]);
+        assert_eq!(writes[11].1, [0x10, 0x80]);
+    }
+
+    #[cfg(feature = "limits")]
+    #[test]
+    fn configure_rail_validates_before_writing() {
+        // WRITE_PROTECT clear, VOUT_MODE exponent -9.
+        let bus = MockBus::with_reads(&[0x00, 0x17, 0x00, 0x17]);
+        let mut pmbus = adaptor(&bus);
+        let config = RailConfig {
+            vout_command: 1.0,
+            limits: &[(CommandCode::VoutCommand, 1.0)],
+            ..Default::default()
+        };
+        let err = block_on(pmbus.configure_rail(0x40, 0, &config)).unwrap_err();
+        assert!(matches!(err, PmbusError::NotALimit));
+        let codes: std::vec::Vec<u8> = bus.writes().iter().map(|(_, w)| w[0]).collect();
+        assert_eq!(codes, [0x10, 0x00, 0x20]);
+
+        // Timing is encoded up front as well.
+        let timing = TimingLimits {
+            ton_rise: f32::NAN,
+            ..Default::default()
+        };
+        let config = RailConfig {
+            vout_command: 1.0,
+            vout_max: Some(1.2),
+            timing: Some(timing),
+            ..Default::default()
+        };
+        let err = block_on(pmbus.configure_rail(0x40, 0, &config)).unwrap_err();
+        assert!(matches!(err, PmbusError::EncodingError));
+        let codes: std::vec::Vec<u8> = bus.writes().iter().map(|(_, w)| w[0]).collect();
+        assert_eq!(codes, [0x10, 0x00, 0x20, 0x10, 0x20]);
+    }
+
+    #[cfg(feature = "limits")]
+    #[test]
+    fn configure_rail_aborts_when_write_protect_returns() {
+        // WRITE_PROTECT clear, VOUT_MODE exponent -9, then WRITE_PROTECT set
+        // again after the VOUT_MAX write.
+        let bus = MockBus::with_reads(&[0x00, 0x17, 0x80]);
+        let mut pmbus = adaptor(&bus);
+        let config = RailConfig {
+            vout_command: 1.0,
+            vout_max: Some(1.2),
+            recheck_write_protect: true,
+            ..Default::default()
+        };
+        let err = block_on(pmbus.configure_rail(0x40, 0, &config)).unwrap_err();
+        assert!(matches!(err, PmbusError::WriteProtected));
+        let codes: std::vec::Vec<u8> = bus.writes().iter().map(|(_, w)| w[0]).collect();
+        assert!(!codes.contains(&CommandCode::VoutCommand.code()));
+    }
+
+    #[test]
+    fn status_bit_by_command_and_mask() {
+        // STATUS_WORD 0x8000 (VOUT), STATUS_CML 0x04 (undefined bit).
+        let bus = MockBus::with_reads(&[0x00, 0x80, 0x04, 0x04]);
+        let mut pmbus = adaptor(&bus);
+        block_on(async {
+            let word = CommandCode::StatusWord;
+            assert!(pmbus.is_status_bit_set(0x40, word, 0x8000).await.unwrap());
+            let cml = CommandCode::StatusCml;
+            assert!(pmbus.is_status_bit_set(0x40, cml, 0x04).await.unwrap());
+            assert!(!pmbus.is_status_bit_set(0x40, cml, 0x0400).await.unwrap());
+            let err = pmbus
+                .is_status_bit_set(0x40, CommandCode::ReadVout, 1)
+                .await;
+            assert!(matches!(err, Err(PmbusError::NotStatusCommand)));
+        });
+    }
+
+    #[test]
+    fn config_fingerprint_is_stable() {
+        let cmds = [
+            CommandCode::OnOffConfig,
+            CommandCode::VoutCommand,
+            CommandCode::UserData00,
+        ];
+        let reads = [0x17, 0x00, 0x02, 2, b'o', b'k'];
+        let fingerprint = |reads: &[u8]| {
+            let bus = MockBus::with_reads(reads);
+            block_on(adaptor(&bus).config_fingerprint(0x40, 0, &cmds)).unwrap()
+        };
+        let golden = fingerprint(&reads);
+        assert_eq!(fingerprint(&reads), golden);
+        assert_ne!(fingerprint(&[0x17, 0x01, 0x02, 2, b'o', b'k']), golden);
+
+        let bus = MockBus::default();
+        let err = block_on(adaptor(&bus).config_fingerprint(0x40, 0, &[CommandCode::Query]));
+        assert!(matches!(err, Err(PmbusError::NotReadable)));
+    }
+
+    #[test]
+    fn mfr_status_width_from_query() {
+        // QUERY says LINEAR11 (word); then two word reads, no second QUERY.
+        let bus = MockBus::with_reads(&[0xA0, 0x00, 0x34, 0x12, 0x78, 0x56]);
+        let mut pmbus = adaptor(&bus);
+        block_on(async {
+            assert_eq!(
+                pmbus.get_status_mfr_specific_auto(0x40).await.unwrap(),
+                0x1234
+            );
+            assert_eq!(
+                pmbus.get_status_mfr_specific_auto(0x40).await.unwrap(),
+                0x5678
+            );
+        });
+        assert_eq!(bus.writes().len(), 3);
+
+        // Bit-field answer: byte read.
+        let bus = MockBus::with_reads(&[0xBC, 0x00, 0x81]);
+        let value = block_on(adaptor(&bus).get_status_mfr_specific_auto(0x40)).unwrap();
+        assert_eq!(value, 0x81);
+    }
+
+    #[test]
+    fn set_margin_keeps_on_bit() {
+        let bus = MockBus::with_reads(&[0x80]);
+        let mut pmbus = adaptor(&bus);
+        let op = block_on(pmbus.set_margin(0x40, MarginDir::Low, MarginFaultAction::Act)).unwrap();
+        assert_eq!(op, 0x98);
+        assert_eq!(bus.writes()[1].1, [0x01, 0x98]);
+    }
+
+    #[test]
+    fn temperature_reads_skip_nacking_sensors() {
+        // TEMPERATURE_1 = 45 °C, TEMPERATURE_2 NACKs, TEMPERATURE_3 = 60 °C.
+        let bus = MockBus::with_reads(&[45, 0x00, 60, 0x00]);
+        bus.nack_code(CommandCode::ReadTemperature2.code());
+        let mut pmbus = adaptor(&bus);
+        assert_eq!(
+            block_on(pmbus.read_hottest_temperature(0x40)).unwrap(),
+            60.0
+        );
+
+        let bus = MockBus::with_reads(&[45, 0x00, 60, 0x00]);
+        bus.nack_code(CommandCode::ReadTemperature2.code());
+        let temps = block_on(adaptor(&bus).read_temperatures(0x40)).unwrap();
+        assert_eq!(temps, [Some(45.0), None, Some(60.0)]);
+
+        let bus = MockBus::default();
+        for cmd in [0x8D, 0x8E, 0x8F] {
+            bus.nack_code(cmd);
+        }
+        let err = block_on(adaptor(&bus).read_hottest_temperature(0x40)).unwrap_err();
+        assert!(matches!(err, PmbusError::Bus(ErrorKind::NoAcknowledge(_))));
+    }
+
+    #[cfg(feature = "limits")]
+    #[test]
+    fn ot_protection_writes_limit_and_response() {
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        let protection = Protection {
+            limit: 125.0,
+            response: FaultResponse {
+                action: FaultAction::Shutdown,
+                retries: FaultResponse::RETRY_FOREVER,
+                delay: 0,
+            },
+        };
+        block_on(pmbus.set_ot_protection(0x40, &protection)).unwrap();
+        let writes = bus.writes();
+        // 125 °C as LINEAR11 is 1000 * 2^-3 = 0xEBE8; shutdown + retry
+        // forever is 0xB8.
+        assert_eq!(writes[0].1, [CommandCode::OtFaultLimit.code(), 0xE8, 0xEB]);
+        assert_eq!(writes[1].1, [CommandCode::OtFaultResponse.code(), 0xB8]);
+
+        let bad = Protection {
+            response: FaultResponse {
+                delay: 8,
+                ..protection.response
+            },
+            ..protection
+        };
+        let err = block_on(pmbus.set_ot_protection(0x40, &bad)).unwrap_err();
+        assert!(matches!(err, PmbusError::EncodingError));
+        assert_eq!(bus.writes().len(), 2);
+    }
+
+    #[test]
+    fn checked_vout_command_rejects_out_of_bounds() {
+        // VOUT_MODE exponent -9; VOUT_MAX = 1.5 V (768), VOUT_MIN = 0.5 V (256).
+        let reads = [0x17, 0x00, 0x03, 0x00, 0x01];
+        let bus = MockBus::with_reads(&reads);
+        let mut pmbus = adaptor(&bus);
+        let err = block_on(pmbus.set_vout_command_volts_checked(0x40, 1.8)).unwrap_err();
+        assert!(matches!(
+            err,
+            PmbusError::VoltageOutOfBounds {
+                requested: 1.8,
+                min: 0.5,
+                max: 1.5
+            }
+        ));
+        assert!(bus.writes().iter().all(|(_, w)| w.len() == 1));
+
+        let bus = MockBus::with_reads(&reads);
+        let mut pmbus = adaptor(&bus);
+        block_on(pmbus.set_vout_command_volts_checked(0x40, 1.0)).unwrap();
+        let writes = bus.writes();
+        assert_eq!(writes[3].1, [CommandCode::VoutCommand.code(), 0x00, 0x02]);
+    }
+
+    #[test]
+    fn raw_and_decoded_share_one_read() {
+        // READ_VIN = 0xD3C0: 960 * 2^-6 = 15 V.
+        let bus = MockBus::with_reads(&[0xC0, 0xD3]);
+        let mut pmbus = adaptor(&bus);
+        let decoded =
+            block_on(pmbus.read_raw_and_decoded(0x40, CommandCode::ReadVin, DataFormat::Linear11));
+        assert_eq!(decoded.unwrap(), (0xD3C0, 15.0));
+        assert_eq!(bus.writes().len(), 1);
+
+        let err = block_on(pmbus.read_raw_and_decoded(0x40, CommandCode::ReadVin, DataFormat::Vid))
+            .unwrap_err();
+        assert!(matches!(err, PmbusError::UnsupportedFormat));
+    }
+
+    #[test]
+    fn frequencies_in_khz() {
+        // READ_FREQUENCY = 500 kHz (0x01F4), sync = 1000 * 2^-1 kHz (0xFBE8).
+        let bus = MockBus::with_reads(&[0xF4, 0x01, 0xE8, 0xFB]);
+        let mut pmbus = adaptor(&bus);
+        assert_eq!(block_on(pmbus.read_frequency_khz(0x40)).unwrap(), 500.0);
+        let err = block_on(pmbus.read_sync_frequency_khz(0x40)).unwrap_err();
+        assert!(matches!(err, PmbusError::NotReadable));
+        pmbus.set_sync_frequency_code(Some(0xD4));
+        assert_eq!(
+            block_on(pmbus.read_sync_frequency_khz(0x40)).unwrap(),
+            500.0
+        );
+        assert_eq!(bus.writes()[1].1, [0xD4]);
+    }
+
+    #[cfg(feature = "fans")]
+    #[test]
+    fn fan_speed_checks_configured_unit() {
+        // FAN_CONFIG_1_2 0xC8: fan 1 installed, commanded in RPM.
+        let bus = MockBus::with_reads(&[0xC8, 0xC8]);
+        let mut pmbus = adaptor(&bus);
+        block_on(pmbus.set_fan_speed_1(0x40, FanSpeed::Rpm(3000.0))).unwrap();
+        // 3000 RPM as LINEAR11 is 750 * 2^2 = 0x12EE.
+        assert_eq!(
+            bus.writes()[1].1,
+            [CommandCode::FanCommand1.code(), 0xEE, 0x12]
+        );
+        let err = block_on(pmbus.set_fan_speed_1(0x40, FanSpeed::DutyPercent(50.0))).unwrap_err();
+        assert!(matches!(err, PmbusError::FanModeMismatch));
+        assert_eq!(bus.writes().len(), 3);
+    }
+
+    #[cfg(feature = "limits")]
+    #[test]
+    fn power_good_thresholds_use_vout_mode() {
+        // VOUT_MODE exponent -9; POWER_GOOD_ON = 0x01CD (0.9 V).
+        let bus = MockBus::with_reads(&[0x17, 0xCD, 0x01, 0x17]);
+        let mut pmbus = adaptor(&bus);
+        let on = block_on(pmbus.get_power_good_on_volts(0x40)).unwrap();
+        assert!((on - 0.9).abs() < 0.002);
+        block_on(pmbus.set_power_good_off_volts(0x40, 0.85)).unwrap();
+        let writes = bus.writes();
+        // 0.85 V -> round(435.2) = 435 = 0x01B3.
+        assert_eq!(writes[3].1, [CommandCode::PowerGoodOff.code(), 0xB3, 0x01]);
+    }
+
+    #[test]
+    fn max_duty_percent_is_range_checked() {
+        // READ_DUTY_CYCLE 45.5 %, then MAX_DUTY 95 % (N=-1, Y=190).
+        let bus = MockBus::with_reads(&[0x5B, 0xF8, 0xBE, 0xF8]);
+        let mut pmbus = adaptor(&bus);
+        block_on(async {
+            for percent in [-0.5, 100.5, f32::NAN] {
+                let err = pmbus.set_max_duty_percent(0x40, percent).await.unwrap_err();
+                assert!(matches!(err, PmbusError::EncodingError));
+            }
+            assert!(bus.writes().is_empty());
+            pmbus.set_max_duty_percent(0x40, 100.0).await.unwrap();
+            pmbus.set_max_duty_percent(0x40, 0.0).await.unwrap();
+            assert_eq!(pmbus.read_duty_cycle_percent(0x40).await.unwrap(), 45.5);
+            assert_eq!(pmbus.get_max_duty_percent(0x40).await.unwrap(), 95.0);
+        });
+        let writes = bus.writes();
+        assert_eq!(writes[0].1[0], CommandCode::MaxDuty.code());
+        assert_eq!(writes[1].1, [0x32, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn pin_accuracy_in_tenths_of_percent() {
+        let bus = MockBus::with_reads(&[0x32]);
+        let mut pmbus = adaptor(&bus);
+        assert_eq!(
+            block_on(pmbus.get_mfr_pin_accuracy_percent(0x40)).unwrap(),
+            5.0
+        );
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn metrics_count_bus_traffic() {
+        let bus = MockBus::with_reads(&[0x17, 0x00, 0x02]);
+        bus.nack_code(CommandCode::ReadIout.code());
+        let mut pmbus = adaptor(&bus);
+        block_on(pmbus.get_vout_mode(0x40)).unwrap();
+        block_on(pmbus.read_vout(0x40)).unwrap();
+        block_on(pmbus.set_vout_command(0x40, 0x0200)).unwrap();
+        let _ = block_on(pmbus.read_iout(0x40));
+        let m = pmbus.metrics();
+        assert_eq!(m.transactions, 4);
+        assert_eq!(m.bytes_written, 1 + 1 + 3 + 1);
+        assert_eq!(m.bytes_read, 1 + 2 + 2);
+        assert_eq!(m.errors, 1);
+        pmbus.reset_metrics();
+        assert_eq!(pmbus.metrics(), Metrics::default());
+    }
+
+    #[test]
+    fn multi_rail_telemetry_per_page() {
+        // Per page: VOUT_MODE, VIN, VOUT, IOUT, TEMPERATURE_1, POUT.
+        let rail = [0x17, 12, 0x00, 0x00, 0x02, 5, 0x00, 40, 0x00, 5, 0x00];
+        let reads: std::vec::Vec<u8> = rail.iter().chain(&rail).copied().collect();
+        let bus = MockBus::with_reads(&reads);
+        let mut pmbus = adaptor(&bus);
+        let rails = block_on(pmbus.read_multi_rail(0x40, [0, 1])).unwrap();
+        let expected = RailTelemetry {
+            vin: 12.0,
+            vout: 1.0,
+            iout: 5.0,
+            temperature: 40.0,
+            pout: 5.0,
+        };
+        assert_eq!(rails, [expected; 2]);
+        let pages: std::vec::Vec<_> = bus
+            .writes()
+            .into_iter()
+            .filter(|(_, w)| w[0] == 0x00)
+            .collect();
+        assert_eq!(pages.len(), 2);
+    }
+
+    #[test]
+    fn multi_rail_same_page_reads_vout_mode_once() {
+        // VOUT_MODE once, then VIN, VOUT, IOUT, TEMPERATURE_1, POUT twice.
+        let rail = [12, 0x00, 0x00, 0x02, 5, 0x00, 40, 0x00, 5, 0x00];
+        let reads: std::vec::Vec<u8> = [0x17].iter().chain(&rail).chain(&rail).copied().collect();
+        let bus = MockBus::with_reads(&reads);
+        let mut pmbus = adaptor(&bus);
+        let rails = block_on(pmbus.read_multi_rail(0x40, [0, 0])).unwrap();
+        assert_eq!(rails[0], rails[1]);
+        assert_eq!(rails[1].vout, 1.0);
+        let count = |code| bus.writes().iter().filter(|(_, w)| w[0] == code).count();
+        assert_eq!(count(0x20), 1);
+        assert_eq!(count(0x00), 1);
+    }
+
+    /// Recovery pins whose SDA stays low for a number of SCL pulses.
+    struct StuckPins {
+        sda_low_for: u8,
+        pulses: u8,
+        scl: bool,
+    }
+
+    impl BusRecoveryPins for StuckPins {
+        type Error = core::convert::Infallible;
+
+        fn set_scl(&mut self, high: bool) -> Result<(), Self::Error> {
+            if high && !self.scl {
+                self.pulses += 1;
+            }
+            self.scl = high;
+            Ok(())
+        }
+
+        fn set_sda(&mut self, _high: bool) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn sda_is_high(&mut self) -> Result<bool, Self::Error> {
+            Ok(self.pulses >= self.sda_low_for)
+        }
+    }
+
+    /// Group bus that logs the frames of each group.
+    #[derive(Default)]
+    struct GroupLog(std::vec::Vec<std::vec::Vec<(u8, std::vec::Vec<u8>)>>);
+
+    impl GroupCommandBus for GroupLog {
+        type Error = core::convert::Infallible;
+
+        async fn write_group(&mut self, frames: &[(u8, &[u8])]) -> Result<(), Self::Error> {
+            self.0
+                .push(frames.iter().map(|&(a, b)| (a, b.to_vec())).collect());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn group_command_builds_frames() {
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        let mut group = GroupLog::default();
+        let margin = [0x18];
+        let vout = [0x66, 0x02];
+        let ops = [
+            (0x40, CommandCode::Operation, &margin[..]),
+            (0x41, CommandCode::VoutCommand, &vout[..]),
+        ];
+        block_on(pmbus.group_command(&mut group, &ops)).unwrap();
+        pmbus.enable_pec(true);
+        block_on(pmbus.group_command(&mut group, &ops[..1])).unwrap();
+        assert_eq!(
+            group.0[0],
+            [
+                (0x40, std::vec![0x01, 0x18]),
+                (0x41, std::vec![0x21, 0x66, 0x02])
+            ]
+        );
+        let pec = pec::crc8(&[0x80, 0x01, 0x18]);
+        assert_eq!(group.0[1], [(0x40, std::vec![0x01, 0x18, pec])]);
+        assert!(bus.writes().is_empty());
+
+        let dup = [ops[0], ops[0]];
+        let err = block_on(pmbus.group_command(&mut group, &dup)).unwrap_err();
+        assert!(matches!(
+            err,
+            PmbusError::DuplicateGroupAddress { addr: 0x40 }
+        ));
+        let err = block_on(pmbus.group_command(&mut group, &[ops[0]; 9])).unwrap_err();
+        assert!(matches!(err, PmbusError::GroupTooLarge));
+        assert_eq!(group.0.len(), 2);
+    }
+
+    #[test]
+    fn bus_recovery_clocks_until_sda_released() {
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        let mut delay = mock::CountingDelay::default();
+        let mut pins = StuckPins {
+            sda_low_for: 3,
+            pulses: 0,
+            scl: true,
+        };
+        block_on(pmbus.bus_recovery(&mut pins, &mut delay)).unwrap();
+        // Three clock pulses, then the STOP.
+        assert_eq!(pins.pulses, 3 + 1);
+
+        let mut pins = StuckPins {
+            sda_low_for: 20,
+            pulses: 0,
+            scl: true,
+        };
+        let err = block_on(pmbus.bus_recovery(&mut pins, &mut delay)).unwrap_err();
+        assert!(matches!(err, PmbusError::BusStuck));
+        assert_eq!(pins.pulses, 9);
+        assert!(bus.writes().is_empty());
+    }
+
+    #[test]
+    fn nonempty_block_read_rejects_zero_count() {
+        let bus = MockBus::with_reads(&[0x00]);
+        let mut pmbus = adaptor(&bus);
+        let err = block_on(pmbus.block_read_nonempty(0x40, CommandCode::MfrId)).unwrap_err();
+        assert!(matches!(err, PmbusError::EmptyBlock));
+
+        let bus = MockBus::with_reads(&[0x02, b'T', b'I']);
+        let mut pmbus = adaptor(&bus);
+        let block = block_on(pmbus.block_read_nonempty(0x40, CommandCode::MfrId)).unwrap();
+        assert_eq!(block.as_slice(), [0x02, b'T', b'I']);
+    }
+
+    #[test]
+    fn regulation_error_in_percent() {
+        // Exponent -9: VOUT_COMMAND 1.0 V (0x0200), READ_VOUT 502/512 V (0x01F6).
+        let bus = MockBus::with_reads(&[0x17, 0x00, 0x02, 0xF6, 0x01]);
+        let mut pmbus = adaptor(&bus);
+        let err = block_on(pmbus.vout_regulation_error(0x40, 0)).unwrap();
+        assert!((err - (502.0 / 512.0 - 1.0) * 100.0).abs() < 1e-4);
+        assert!(err < 0.0);
+    }
+
+    #[test]
+    fn assert_commands_checks_query_format() {
+        // READ_VIN: supported, readable, LINEAR11 (0xA0). READ_VOUT: supported,
+        // readable, ULINEAR16 (0xA4) but expected LINEAR11. Then unsupported.
+        let bus = MockBus::with_reads(&[0xA0, 0x00, 0xA4, 0x00, 0x00, 0x00]);
+        let mut pmbus = adaptor(&bus);
+        let expectations = [
+            (CommandCode::ReadVin, DataFormat::Linear11),
+            (CommandCode::ReadVout, DataFormat::Linear11),
+        ];
+        let err = block_on(pmbus.assert_commands(0x40, &expectations)).unwrap_err();
+        assert!(matches!(
+            err,
+            PmbusError::UnexpectedFormat {
+                cmd: CommandCode::ReadVout
+            }
+        ));
+        let err = block_on(pmbus.assert_commands(0x40, &expectations[..1])).unwrap_err();
+        assert!(matches!(
+            err,
+            PmbusError::CommandUnsupported {
+                cmd: CommandCode::ReadVin
+            }
+        ));
+    }
+
+    #[test]
+    fn iout_direction_keeps_linear11_sign() {
+        // -2.0 A (N=-1, Y=-4) then 0.0 A then +2.0 A.
+        let bus = MockBus::with_reads(&[0xFC, 0xFF, 0x00, 0x00, 0x04, 0xF8]);
+        let mut pmbus = adaptor(&bus);
+        assert_eq!(
+            block_on(pmbus.read_iout_direction(0x40)).unwrap(),
+            CurrentDirection::Sinking
+        );
+        assert_eq!(
+            block_on(pmbus.read_iout_direction(0x40)).unwrap(),
+            CurrentDirection::Idle
+        );
+        assert_eq!(
+            block_on(pmbus.read_iout_direction(0x40)).unwrap(),
+            CurrentDirection::Sourcing
+        );
+    }
+
+    #[test]
+    fn enable_via_operation_sets_on_off_config_first() {
+        // ON_OFF_CONFIG 0x06 (pin control), read back 0x1E, OPERATION 0x00.
+        let bus = MockBus::with_reads(&[0x06, 0x1E, 0x00]);
+        let mut pmbus = adaptor(&bus);
+        block_on(pmbus.enable_via_operation(0x40)).unwrap();
+        let writes: std::vec::Vec<_> = bus
+            .writes()
+            .into_iter()
+            .filter(|(_, w)| w.len() == 2)
+            .map(|(_, w)| w)
+            .collect();
+        assert_eq!(writes, [std::vec![0x02, 0x1E], std::vec![0x01, 0x80]]);
+
+        // The device ignores the ON_OFF_CONFIG write.
+        let bus = MockBus::with_reads(&[0x06, 0x06]);
+        let mut pmbus = adaptor(&bus);
+        assert!(matches!(
+            block_on(pmbus.enable_via_operation(0x40)),
+            Err(PmbusError::OnOffConfigRejected)
+        ));
+        assert!(bus.writes().iter().all(|(_, w)| w[0] != 0x01));
+    }
+
+    #[cfg(feature = "limits")]
+    #[test]
+    fn read_all_fault_responses_decodes_each_command() {
+        let reads: [u8; 12] = core::array::from_fn(|i| i as u8);
+        let bus = MockBus::with_reads(&reads);
+        let mut pmbus = adaptor(&bus);
+        let all = block_on(pmbus.read_all_fault_responses(0x40)).unwrap();
+        assert_eq!(all.vout_ov, FaultResponse::from_raw(0));
+        assert_eq!(all.iout_oc_lv, FaultResponse::from_raw(3));
+        assert_eq!(all.pout_op.delay, 3);
+        assert_eq!(all.pout_op.retries, 1);
+        let codes: std::vec::Vec<u8> = bus.writes().iter().map(|(_, w)| w[0]).collect();
+        assert_eq!(
+            codes,
+            [
+                0x41, 0x45, 0x47, 0x49, 0x4C, 0x50, 0x54, 0x56, 0x5A, 0x5C, 0x63, 0x69
+            ]
+        );
+    }
+
+    #[test]
+    fn read_vout_f32_decodes_with_vout_mode() {
+        // VOUT_MODE exponent -9, READ_VOUT 0x0600 = 3.0 V.
+        let bus = MockBus::with_reads(&[0x17, 0x00, 0x06]);
+        let mut pmbus = adaptor(&bus);
+        assert_eq!(block_on(pmbus.read_vout_f32(0x40)).unwrap(), 3.0);
+
+        // VID mode.
+        let bus = MockBus::with_reads(&[0x20]);
+        let mut pmbus = adaptor(&bus);
+        assert!(matches!(
+            block_on(pmbus.read_vout_f32(0x40)),
+            Err(PmbusError::UnsupportedVoutMode)
+        ));
+        assert_eq!(bus.writes().len(), 1);
+    }
+
+    #[test]
+    fn vout_mode_cache_skips_vout_mode_reads() {
+        // VOUT_MODE -9, then READ_VOUT 3.0 V twice, then VOUT_MODE -8 and
+        // READ_VOUT 6.0 V after invalidation.
+        let bus = MockBus::with_reads(&[0x17, 0x00, 0x06, 0x00, 0x06, 0x18, 0x00, 0x06]);
+        let mut pmbus = adaptor(&bus);
+        block_on(async {
+            assert_eq!(pmbus.read_vout_f32_cached(0x40).await.unwrap(), 3.0);
+            assert_eq!(pmbus.read_vout_f32_cached(0x40).await.unwrap(), 3.0);
+            assert_eq!(pmbus.cached_vout_mode(0x40), Some(VoutMode::from_raw(0x17)));
+            pmbus.invalidate_vout_mode_cache(0x40);
+            assert_eq!(pmbus.read_vout_f32_cached(0x40).await.unwrap(), 6.0);
+        });
+        let codes: std::vec::Vec<u8> = bus.writes().iter().map(|(_, w)| w[0]).collect();
+        assert_eq!(codes, [0x20, 0x8B, 0x8B, 0x20, 0x8B]);
+    }
+
+    #[test]
+    fn vout_mode_cache_follows_page_changes() {
+        // VOUT_MODE -9 cached on page 0; read_all_vout then leaves page 1
+        // (exponent -12, 3.3 V) active.
+        let bus = MockBus::with_reads(&[
+            0x17, //
+            0x17, 0x00, 0x02, 0xFF, 0x01, //
+            0x14, 0xCD, 0x34, 0xCD, 0x34, //
+            0x14, 0xCD, 0x34,
+        ]);
+        let mut pmbus = adaptor(&bus);
+        block_on(async {
+            pmbus.select_page(0x40, 0).await.unwrap();
+            pmbus.cache_vout_mode(0x40).await.unwrap();
+            pmbus.read_all_vout(0x40, &[0, 1]).await.unwrap();
+            assert_eq!(pmbus.cached_vout_mode(0x40), None);
+            let volts = pmbus.read_vout_f32_cached(0x40).await.unwrap();
+            assert!((volts - 3.3).abs() < 0.001);
+
+            pmbus.recover_bus(0x40).await.unwrap();
+            assert_eq!(pmbus.cached_vout_mode(0x40), None);
+        });
+    }
+
+    #[cfg(feature = "fans")]
+    #[test]
+    fn linear11_telemetry_decodes() {
+        // 45.5 °C (N=-1, Y=91), then 3000 RPM (N=3, Y=375).
+        let bus = MockBus::with_reads(&[0x5B, 0xF8, 0x77, 0x19]);
+        let mut pmbus = adaptor(&bus);
+        assert_eq!(
+            block_on(pmbus.read_temperature_2_celsius(0x40)).unwrap(),
+            45.5
+        );
+        assert_eq!(block_on(pmbus.read_fan_speed_1_rpm(0x40)).unwrap(), 3000.0);
+        let codes: std::vec::Vec<u8> = bus.writes().iter().map(|(_, w)| w[0]).collect();
+        assert_eq!(codes, [0x8E, 0x90]);
+
+        // The `*_f32` names decode the same way, inversion included.
+        let bus = MockBus::with_reads(&[0x5B, 0xF8, 0x5B, 0xF8]);
+        let mut pmbus = adaptor(&bus);
+        pmbus.set_invert_iout(true);
+        assert_eq!(block_on(pmbus.read_temperature_2_f32(0x40)).unwrap(), 45.5);
+        assert_eq!(block_on(pmbus.read_iout_f32(0x40)).unwrap(), -45.5);
+        let codes: std::vec::Vec<u8> = bus.writes().iter().map(|(_, w)| w[0]).collect();
+        assert_eq!(codes, [0x8E, 0x8C]);
+    }
+
+    #[test]
+    fn read_all_status_reads_fans_34_when_present() {
+        // STATUS_WORD FANS, STATUS_FANS_1_2 fan 1 fault, QUERY supported,
+        // STATUS_FANS_3_4 fan 3 fault; then the same without the QUERY.
+        let bus =
+            MockBus::with_reads(&[0x00, 0x04, 0x80, 0x80, 0x00, 0x80, 0x00, 0x04, 0x80, 0x80]);
+        let mut pmbus = adaptor(&bus);
+        for _ in 0..2 {
+            let snap = block_on(pmbus.read_all_status(0x40)).unwrap();
+            assert_eq!(snap.fans_12, StatusFans12::from_raw(0x80));
+            assert_eq!(snap.fans_34, StatusFans34::from_raw(0x80));
+        }
+        let codes: std::vec::Vec<u8> = bus.writes().iter().map(|(_, w)| w[0]).collect();
+        assert_eq!(codes, [0x79, 0x81, 0x1A, 0x82, 0x79, 0x81, 0x82]);
+
+        // Without QUERY support, only a clear STATUS_FANS_1_2 leads on.
+        let bus = MockBus::with_reads(&[0x00, 0x04, 0x80]);
+        bus.nack_code(CommandCode::Query.code());
+        let mut pmbus = adaptor(&bus);
+        let snap = block_on(pmbus.read_all_status(0x40)).unwrap();
+        assert!(snap.fans_34.is_empty());
+        let codes: std::vec::Vec<u8> = bus.writes().iter().map(|(_, w)| w[0]).collect();
+        assert_eq!(codes, [0x79, 0x81, 0x1A]);
+    }
+
+    #[cfg(feature = "limits")]
+    #[test]
+    fn vin_uvlo_rejects_inverted_thresholds() {
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        block_on(async {
+            let err = pmbus.set_vin_uvlo(0x40, 8.0, 8.5).await.unwrap_err();
+            assert!(matches!(err, PmbusError::InvalidHysteresis));
+            assert!(pmbus.set_vin_uvlo(0x40, 8.0, 8.0).await.is_err());
+        });
+        assert!(bus.writes().is_empty());
+
+        block_on(pmbus.set_vin_uvlo(0x40, 9.0, 8.5)).unwrap();
+        let writes = bus.writes();
+        assert_eq!(writes.len(), 2);
+        assert_eq!(writes[0].1[0], CommandCode::VinOff.code());
+        assert_eq!(writes[1].1[0], CommandCode::VinOn.code());
+    }
+
+    #[test]
+    fn probe_rejects_floating_bus() {
+        // No scripted reads: the mock answers 0xFF like an undriven bus.
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        let err = block_on(pmbus.probe_pmbus(0x40)).unwrap_err();
+        assert!(matches!(err, PmbusError::NotPmbus));
+    }
+
+    #[test]
+    fn vout_trim_volts_writes_twos_complement() {
+        // VOUT_MODE 0x17: ULINEAR16, exponent -9.
+        let bus = MockBus::with_reads(&[0x17]);
+        let mut pmbus = adaptor(&bus);
+        block_on(pmbus.set_vout_trim_volts(0x40, -0.05)).unwrap();
+        let writes = bus.writes();
+        assert_eq!(writes[1].1, [CommandCode::VoutTrim.code(), 0xE6, 0xFF]);
+    }
+
+    #[test]
+    fn vout_trim_volts_reads_back_negative() {
+        // VOUT_MODE exponent -9, VOUT_TRIM = 0xFFE6 (-26 LSB).
+        let bus = MockBus::with_reads(&[0x17, 0xE6, 0xFF]);
+        let mut pmbus = adaptor(&bus);
+        let trim = block_on(pmbus.get_vout_trim_volts(0x40)).unwrap();
+        assert_eq!(trim, -26.0 / 512.0);
+    }
+
+    #[test]
+    fn vout_trim_volts_rejects_direct_mode() {
+        let bus = MockBus::with_reads(&[0x40]);
+        let mut pmbus = adaptor(&bus);
+        let err = block_on(pmbus.set_vout_trim_volts(0x40, 0.01)).unwrap_err();
+        assert!(matches!(err, PmbusError::UnsupportedVoutMode));
+    }
+
+    #[test]
+    fn vout_settles_after_ramp() {
+        // VOUT_MODE exponent -9, then READ_VOUT 0.5 V followed by 1.0 V.
+        let bus = MockBus::with_reads(&[0x17, 0x00, 0x01, 0x00, 0x02]);
+        let mut pmbus = adaptor(&bus);
+        let mut delay = mock::CountingDelay::default();
+        let vout = block_on(pmbus.set_vout_and_settle(0x40, 1.0, 0.01, 10, &mut delay)).unwrap();
+        assert_eq!(vout, 1.0);
+        assert_eq!(delay.ms, 1);
+        assert_eq!(
+            bus.writes()[1].1,
+            [CommandCode::VoutCommand.code(), 0x00, 0x02]
+        );
+    }
+
+    #[test]
+    fn vout_settle_times_out() {
+        // READ_VOUT keeps returning 0xFFFF once the script runs out.
+        let bus = MockBus::with_reads(&[0x17]);
+        let mut pmbus = adaptor(&bus);
+        let mut delay = mock::CountingDelay::default();
+        let err = block_on(pmbus.set_vout_and_settle(0x40, 1.0, 0.01, 5, &mut delay)).unwrap_err();
+        assert!(matches!(err, PmbusError::SettleTimeout));
+        assert_eq!(delay.ms, 5);
+    }
+
+    #[test]
+    fn vout_command_volts_rejects_relative_mode() {
+        // VOUT_MODE 0x97: relative, ULINEAR16, exponent -9.
+        let bus = MockBus::with_reads(&[0x97]);
+        let mut pmbus = adaptor(&bus);
+        let err = block_on(pmbus.set_vout_command_volts(0x40, 1.0)).unwrap_err();
+        assert!(matches!(err, PmbusError::RelativeModeUnsupported));
+        assert_eq!(bus.writes().len(), 1);
+    }
+
+    #[test]
+    fn block_write_rejects_oversized_payload() {
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        let err = block_on(pmbus.raw_block_write(0x40, 0xD0, &[0u8; 256])).unwrap_err();
+        assert!(matches!(err, PmbusError::BlockTooLong));
+        assert!(bus.writes().is_empty());
+
+        block_on(pmbus.raw_block_write(0x40, 0xD0, &[0u8; 255])).unwrap();
+        assert_eq!(bus.writes()[0].1[..2], [0xD0, 255]);
+    }
+
+    fn pec_byte_read(addr: u8, code: u8, data: u8) -> u8 {
+        Pec::new()
+            .addr_write(addr)
+            .update(code)
+            .addr_read(addr)
+            .update(data)
+            .finish()
+    }
+
+    #[test]
+    fn pec_policy_on_mismatch() {
+        let good = pec_byte_read(0x40, 0x79, 0x5A);
+        let bad = good ^ 1;
+
+        let bus = MockBus::with_reads(&[0x5A, bad]);
+        let mut pmbus = adaptor(&bus);
+        pmbus.set_pec_mode(PecMode::On);
+        let err = block_on(pmbus.raw_read_byte(0x40, 0x79)).unwrap_err();
+        assert!(matches!(
+            err,
+            PmbusError::PecMismatch { expected, received } if expected == good && received == bad
+        ));
+
+        let bus = MockBus::with_reads(&[0x5A, bad, 0x5A, good]);
+        let mut pmbus = adaptor(&bus);
+        pmbus.set_pec_mode(PecMode::On);
+        pmbus.set_pec_policy(PecPolicy::Retry(1));
+        assert_eq!(block_on(pmbus.raw_read_byte(0x40, 0x79)).unwrap(), 0x5A);
+        assert_eq!(bus.writes().len(), 2);
+
+        let bus = MockBus::with_reads(&[0x5A, bad]);
+        let mut pmbus = adaptor(&bus);
+        pmbus.set_pec_mode(PecMode::On);
+        pmbus.set_pec_policy(PecPolicy::WarnAndAccept);
+        assert_eq!(block_on(pmbus.raw_read_byte(0x40, 0x79)).unwrap(), 0x5A);
+        assert_eq!(pmbus.pec_mismatch_count(), 1);
+    }
+
+    #[test]
+    fn enable_pec_checks_word_reads() {
+        let bus = MockBus::with_reads(&[0x34, 0x12, 0x00]);
+        let mut pmbus = adaptor(&bus);
+        pmbus.enable_pec(true);
+        assert_eq!(pmbus.pec_mode(), PecMode::On);
+        let err = block_on(pmbus.get_vout_command(0x40)).unwrap_err();
+        assert!(matches!(err, PmbusError::PecMismatch { received: 0, .. }));
+
+        block_on(pmbus.set_vout_command(0x40, 0x1234)).unwrap();
+        let pec = pec::crc8(&[0x80, 0x21, 0x34, 0x12]);
+        assert_eq!(bus.writes()[1].1, [0x21, 0x34, 0x12, pec]);
+
+        pmbus.enable_pec(false);
+        assert_eq!(pmbus.pec_mode(), PecMode::Off);
+    }
+
+    #[test]
+    fn verified_store_compares_canary() {
+        let bus = MockBus::with_reads(&[0x66, 0x02, 0x00, 0x00, 0x66, 0x02]);
+        let mut pmbus = adaptor(&bus);
+        let mut delay = mock::CountingDelay::default();
+        pmbus.set_store_canary(Some(CommandCode::VoutCommand));
+        block_on(pmbus.store_user_all_verified(0x40, 10, &mut delay)).unwrap();
+        let writes = bus.writes();
+        let codes: [u8; 6] = core::array::from_fn(|i| writes[i].1[0]);
+        assert_eq!(codes, [0x21, 0x15, 0x78, 0x16, 0x78, 0x21]);
+        assert_eq!(delay.ms, 0);
+
+        let bus = MockBus::with_reads(&[0x66, 0x02, 0x00, 0x00, 0x00, 0x02]);
+        let mut pmbus = adaptor(&bus);
+        pmbus.set_store_canary(Some(CommandCode::VoutCommand));
+        let err = block_on(pmbus.store_user_all_verified(0x40, 10, &mut delay)).unwrap_err();
+        assert!(matches!(err, PmbusError::StoreFailed));
+    }
+
+    #[test]
+    fn verified_store_retries_nacked_restore() {
+        let bus = MockBus::with_reads(&[0x66, 0x02, 0x00, 0x00, 0x66, 0x02]);
+        bus.nack_code_once(0x16);
+        let mut pmbus = adaptor(&bus);
+        let mut delay = mock::CountingDelay::default();
+        pmbus.set_store_canary(Some(CommandCode::VoutCommand));
+        block_on(pmbus.store_user_all_verified(0x40, 10, &mut delay)).unwrap();
+        let writes = bus.writes();
+        let codes: [u8; 7] = core::array::from_fn(|i| writes[i].1[0]);
+        assert_eq!(codes, [0x21, 0x15, 0x78, 0x16, 0x16, 0x78, 0x21]);
+        assert_eq!(delay.ms, 1);
+
+        let bus = MockBus::with_reads(&[0x66, 0x02, 0x00]);
+        bus.nack_code(0x16);
+        let mut pmbus = adaptor(&bus);
+        let mut delay = mock::CountingDelay::default();
+        pmbus.set_store_canary(Some(CommandCode::VoutCommand));
+        let err = block_on(pmbus.store_user_all_verified(0x40, 3, &mut delay)).unwrap_err();
+        assert!(matches!(err, PmbusError::DeviceBusy));
+        assert_eq!(delay.ms, 3);
+    }
+
+    #[test]
+    fn smbalert_mask_typed_write() {
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        let mask = StatusValue::Vout(StatusVout::from_raw(0x80));
+        block_on(pmbus.set_smbalert_mask_typed(0x40, mask)).unwrap();
+        assert_eq!(bus.writes()[0].1, [0x1B, 0x7A, 0x80]);
+
+        let word = StatusValue::Word(StatusWord::empty());
+        let err = block_on(pmbus.set_smbalert_mask_typed(0x40, word)).unwrap_err();
+        assert!(matches!(err, PmbusError::NotStatusCommand));
+    }
+
+    #[cfg(feature = "identification")]
+    #[test]
+    fn block_read_pec_covers_address_bytes() {
+        // Block read of 0x99 at 0x40: [0x80, 0x99, 0x81, count, data..].
+        let pec = pec::crc8(&[0x80, 0x99, 0x81, 2, b'T', b'I']);
+        let bus = MockBus::with_reads(&[2, b'T', b'I', pec]);
+        let mut pmbus = adaptor(&bus);
+        pmbus.set_pec_mode(PecMode::On);
+        let block = block_on(pmbus.get_mfr_id(0x40)).unwrap();
+        assert_eq!(block.as_slice(), &[2, b'T', b'I']);
+    }
+
+    #[cfg(feature = "limits")]
+    #[test]
+    fn generic_limit_encoding() {
+        // VOUT_OV_FAULT_LIMIT uses VOUT_MODE (exponent -9); OT_FAULT_LIMIT is LINEAR11.
+        let bus = MockBus::with_reads(&[0x17]);
+        let mut pmbus = adaptor(&bus);
+        block_on(async {
+            pmbus
+                .set_limit(0x40, CommandCode::VoutOvFaultLimit, 1.5)
+                .await
+                .unwrap();
+            pmbus
+                .set_limit(0x40, CommandCode::OtFaultLimit, 125.0)
+                .await
+                .unwrap();
+            let err = pmbus
+                .set_limit(0x40, CommandCode::VoutCommand, 1.0)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, PmbusError::NotALimit));
+        });
+        let writes = bus.writes();
+        assert_eq!(writes[1].1, [0x40, 0x00, 0x03]);
+        let ot = Linear11::from_f32(125.0).unwrap().raw().to_le_bytes();
+        assert_eq!(writes[2].1, [0x4F, ot[0], ot[1]]);
+        assert_eq!(writes.len(), 3);
+    }
+
+    #[cfg(feature = "limits")]
+    #[test]
+    fn timing_limits_roundtrip() {
+        let limits = TimingLimits {
+            ton_delay: 5.0,
+            ton_rise: 2.5,
+            ton_max_fault_limit: 10.0,
+            toff_delay: 1.0,
+            toff_fall: 3.0,
+            toff_max_warn_limit: 0.0,
+        };
+        let bus = MockBus::default();
+        let mut pmbus = adaptor(&bus);
+        block_on(pmbus.apply_timing_limits(0x40, &limits)).unwrap();
+        let writes = bus.writes();
+        let codes: [u8; 6] = core::array::from_fn(|i| writes[i].1[0]);
+        assert_eq!(codes, [0x60, 0x61, 0x62, 0x64, 0x65, 0x66]);
+
+        let reads: std::vec::Vec<u8> = writes
+            .iter()
+            .flat_map(|w| w.1[1..].iter().copied())
+            .collect();
+        let bus = MockBus::with_reads(&reads);
+        let mut pmbus = adaptor(&bus);
+        assert_eq!(block_on(pmbus.read_timing_limits(0x40)).unwrap(), limits);
+    }
+
+    #[test]
+    fn read_coefficients_for_queries_command_and_direction() {
+        // m = 100, b = -10, R = -2.
+        let bus = MockBus::with_reads(&[5, 0x64, 0x00, 0xF6, 0xFF, 0xFE]);
+        let mut pmbus = adaptor(&bus);
+        let coefficients = block_on(pmbus.read_coefficients_for(
+            0x40,
+            CommandCode::ReadIout,
+            CoeffDirection::Write,
+        ))
+        .unwrap();
+        assert_eq!(coefficients, DirectCoefficients::new(100, -10, -2));
+        assert_eq!(bus.writes()[0].1, [0x30, 2, 0x8C, 0]);
+    }
+
+    #[test]
+    fn short_block_reports_lengths() {
+        // COEFFICIENTS answers with only 3 of the 5 data bytes.
+        let bus = MockBus::with_reads(&[3, 1, 0, 0]);
+        let mut pmbus = adaptor(&bus);
+        let err =
+            block_on(pmbus.get_coefficients(0x40, CommandCode::ReadVout, CoeffDirection::Read))
+                .unwrap_err();
+        assert!(matches!(
+            err,
+            PmbusError::ShortRead {
+                expected: 5,
+                got: 3
+            }
+        ));
+        assert_eq!(bus.writes()[0].1, [0x30, 2, 0x8B, 1]);
+
+        // A full 32-byte block fits.
+        let mut reads = std::vec![32];
+        reads.extend(1..=32);
+        let bus = MockBus::with_reads(&reads);
+        let mut pmbus = adaptor(&bus);
+        let block = block_on(pmbus.raw_block_read(0x40, 0xD0)).unwrap();
+        assert_eq!(block.as_slice(), reads.as_slice());
+
+        // A count byte larger than a block can carry.
+        let bus = MockBus::with_reads(&[40]);
+        let mut pmbus = adaptor(&bus);
+        let err = block_on(pmbus.raw_block_read(0x40, 0xD0)).unwrap_err();
+        assert!(matches!(
+            err,
+            PmbusError::ShortRead {
+                expected: 40,
+                got: 32
+            }
+        ));
+    }
+}
@@ -0,0 +1,37 @@
+use embedded_hal_async::i2c::Operation;
+
+/// Bus traffic counted by the adapter since it was created or last reset.
+///
+/// Counts what actually reached the bus: dry-run writes are not included,
+/// while PEC bytes, PEC retries and bus recovery frames are.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Metrics {
+    /// I2C transactions issued.
+    pub transactions: u32,
+    /// Bytes written after the address, PEC included.
+    pub bytes_written: u32,
+    /// Bytes read, PEC included.
+    pub bytes_read: u32,
+    /// Transactions that returned a bus error.
+    pub errors: u32,
+}
+
+impl Metrics {
+    /// Account for one transaction made of `ops`.
+    pub(crate) fn count(&mut self, ops: &[Operation<'_>], ok: bool) {
+        self.transactions = self.transactions.wrapping_add(1);
+        for op in ops {
+            match op {
+                Operation::Write(bytes) => {
+                    self.bytes_written = self.bytes_written.wrapping_add(bytes.len() as u32);
+                }
+                Operation::Read(buf) => {
+                    self.bytes_read = self.bytes_read.wrapping_add(buf.len() as u32);
+                }
+            }
+        }
+        if !ok {
+            self.errors = self.errors.wrapping_add(1);
+        }
+    }
+}
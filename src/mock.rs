@@ -0,0 +1,156 @@
+//! Scripted I2C bus and a busy-polling executor for adapter tests.
+
+extern crate std;
+
+use core::cell::RefCell;
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+use std::rc::Rc;
+use std::vec::Vec;
+
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::{ErrorKind, ErrorType, I2c, NoAcknowledgeSource, Operation};
+use smbus_adapter::SmbusAdaptor;
+
+use crate::PmbusAdaptor;
+
+/// Bus activity shared between a `MockBus` and the test that owns it.
+#[derive(Default)]
+pub struct MockState {
+    /// Bytes written per transaction, as `(address, bytes)`.
+    pub writes: Vec<(u8, Vec<u8>)>,
+    /// Bytes handed out to read operations, in order. Exhausted reads return 0xFF.
+    pub reads: Vec<u8>,
+    read_pos: usize,
+    /// Command codes whose transactions are NACKed without consuming reads.
+    pub nacks: Vec<u8>,
+    /// Command codes whose next transaction alone is NACKed.
+    pub nacks_once: Vec<u8>,
+    /// Index of a transaction that fails with a bus error, counting from 0.
+    pub fail_at: Option<usize>,
+    transactions: usize,
+}
+
+/// An I2C bus that logs written bytes and answers reads from a script.
+#[derive(Clone, Default)]
+pub struct MockBus(pub Rc<RefCell<MockState>>);
+
+impl MockBus {
+    /// A bus whose reads return `reads` in order.
+    pub fn with_reads(reads: &[u8]) -> Self {
+        let bus = Self::default();
+        bus.0.borrow_mut().reads.extend_from_slice(reads);
+        bus
+    }
+
+    /// NACK every transaction that starts with command `code`.
+    pub fn nack_code(&self, code: u8) {
+        self.0.borrow_mut().nacks.push(code);
+    }
+
+    /// NACK only the next transaction that starts with command `code`.
+    pub fn nack_code_once(&self, code: u8) {
+        self.0.borrow_mut().nacks_once.push(code);
+    }
+
+    /// Fail transaction number `n` (counting from 0) with a bus error.
+    pub fn fail_transaction(&self, n: usize) {
+        self.0.borrow_mut().fail_at = Some(n);
+    }
+
+    /// All write frames seen so far.
+    pub fn writes(&self) -> Vec<(u8, Vec<u8>)> {
+        self.0.borrow().writes.clone()
+    }
+}
+
+impl ErrorType for MockBus {
+    type Error = ErrorKind;
+}
+
+impl I2c for MockBus {
+    async fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.run(address, operations)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl embedded_hal::i2c::I2c for MockBus {
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.run(address, operations)
+    }
+}
+
+impl MockBus {
+    fn run(&self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), ErrorKind> {
+        let mut state = self.0.borrow_mut();
+        state.transactions += 1;
+        if state.fail_at == Some(state.transactions - 1) {
+            return Err(ErrorKind::Bus);
+        }
+        if let Some(Operation::Write([code, ..])) = operations.first() {
+            let once = state.nacks_once.iter().position(|c| c == code);
+            if let Some(i) = once {
+                state.nacks_once.remove(i);
+            }
+            if once.is_some() || state.nacks.contains(code) {
+                state.writes.push((address, std::vec![*code]));
+                return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data));
+            }
+        }
+        let mut written = Vec::new();
+        for op in operations {
+            match op {
+                Operation::Write(bytes) => written.extend_from_slice(bytes),
+                Operation::Read(buf) => {
+                    for b in buf.iter_mut() {
+                        *b = state.reads.get(state.read_pos).copied().unwrap_or(0xFF);
+                        state.read_pos += 1;
+                    }
+                }
+            }
+        }
+        if !written.is_empty() {
+            state.writes.push((address, written));
+        }
+        Ok(())
+    }
+}
+
+/// A delay that returns immediately and counts the time requested.
+#[derive(Default)]
+pub struct CountingDelay {
+    /// Total delay requested, in whole milliseconds.
+    pub ms: u32,
+}
+
+impl DelayNs for CountingDelay {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.ms += ns / 1_000_000;
+    }
+}
+
+/// Wrap a clone of `bus` in a `PmbusAdaptor`.
+pub fn adaptor(bus: &MockBus) -> PmbusAdaptor<MockBus> {
+    PmbusAdaptor::new(SmbusAdaptor::new(bus.clone()))
+}
+
+/// Drive a future to completion by polling it with a no-op waker.
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+    }
+}
@@ -0,0 +1,61 @@
+/// Margin state selected by OPERATION (0x01) bits \[5:4\].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarginDir {
+    /// Regulate to VOUT_COMMAND.
+    None,
+    /// Regulate to VOUT_MARGIN_LOW.
+    Low,
+    /// Regulate to VOUT_MARGIN_HIGH.
+    High,
+}
+
+/// How faults are handled while margined, OPERATION bits \[3:2\].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarginFaultAction {
+    /// Ignore faults caused by the margined voltage.
+    Ignore,
+    /// Act on faults as configured by the fault responses.
+    Act,
+}
+
+/// OPERATION bit 7: output on.
+pub(crate) const OPERATION_ON: u8 = 0x80;
+
+/// ON_OFF_CONFIG bits 4 and 3: power up only when commanded, and obey the
+/// on/off bit of OPERATION.
+pub(crate) const ON_OFF_USE_OPERATION: u8 = 0x18;
+
+/// OPERATION bits owned by the margin state.
+pub(crate) const MARGIN_MASK: u8 = 0x3C;
+
+/// Compose OPERATION bits \[5:2\] for a margin state.
+///
+/// The fault action only applies while margined; with [`MarginDir::None`]
+/// bits \[3:2\] are left clear.
+pub fn margin_bits(direction: MarginDir, fault_action: MarginFaultAction) -> u8 {
+    let dir = match direction {
+        MarginDir::None => return 0,
+        MarginDir::Low => 0x10,
+        MarginDir::High => 0x20,
+    };
+    let action = match fault_action {
+        MarginFaultAction::Ignore => 0x04,
+        MarginFaultAction::Act => 0x08,
+    };
+    dir | action
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn margin_encodings() {
+        use MarginFaultAction::*;
+        assert_eq!(margin_bits(MarginDir::Low, Ignore), 0x14);
+        assert_eq!(margin_bits(MarginDir::Low, Act), 0x18);
+        assert_eq!(margin_bits(MarginDir::High, Ignore), 0x24);
+        assert_eq!(margin_bits(MarginDir::High, Act), 0x28);
+        assert_eq!(margin_bits(MarginDir::None, Act), 0x00);
+    }
+}
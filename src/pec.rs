@@ -0,0 +1,117 @@
+use crate::commands::CommandCode;
+
+/// How the adapter applies SMBus Packet Error Checking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PecMode {
+    /// Never append or expect a PEC byte (default).
+    #[default]
+    Off,
+    /// Append/verify PEC on every transaction.
+    On,
+    /// Consult the adapter's `pec_required` hook per command.
+    Auto,
+}
+
+/// What the adapter does when a received PEC byte doesn't match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PecPolicy {
+    /// Return [`PmbusError::PecMismatch`](crate::PmbusError::PecMismatch)
+    /// (default).
+    #[default]
+    Fail,
+    /// Re-issue the read up to `n` more times, then fail.
+    Retry(u8),
+    /// Count the mismatch and return the data as received.
+    WarnAndAccept,
+}
+
+/// Default `pec_required` hook used in [`PecMode::Auto`].
+///
+/// Requires PEC on everything except the telemetry and energy reads
+/// (0x83..=0x97), matching devices that enforce PEC on configuration but
+/// stream telemetry without it.
+pub fn default_pec_required(cmd: CommandCode) -> bool {
+    !(CommandCode::ReadKwhIn.code()..=CommandCode::ReadPin.code()).contains(&cmd.code())
+}
+
+/// Incremental SMBus PEC (CRC-8, polynomial x^8 + x^2 + x + 1, init 0).
+///
+/// The PEC covers every byte on the wire, including the address bytes with
+/// their R/W bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Pec(u8);
+
+impl Pec {
+    /// Start a new PEC computation.
+    pub fn new() -> Self {
+        Self(0)
+    }
+
+    /// Feed a single byte.
+    pub fn update(self, byte: u8) -> Self {
+        let mut crc = self.0 ^ byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+        Self(crc)
+    }
+
+    /// Feed a slice of bytes.
+    pub fn bytes(self, data: &[u8]) -> Self {
+        data.iter().fold(self, |pec, &b| pec.update(b))
+    }
+
+    /// Feed the address byte of a write (`addr << 1`).
+    pub fn addr_write(self, addr: u8) -> Self {
+        self.update(addr << 1)
+    }
+
+    /// Feed the address byte of a read (`addr << 1 | 1`).
+    pub fn addr_read(self, addr: u8) -> Self {
+        self.update((addr << 1) | 1)
+    }
+
+    /// Return the computed PEC byte.
+    pub fn finish(self) -> u8 {
+        self.0
+    }
+}
+
+/// Compute the SMBus PEC of a complete byte sequence.
+pub fn crc8(data: &[u8]) -> u8 {
+    Pec::new().bytes(data).finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc8_check_value() {
+        // CRC-8/SMBUS check value for "123456789".
+        assert_eq!(crc8(b"123456789"), 0xF4);
+        assert_eq!(crc8(&[]), 0x00);
+    }
+
+    #[test]
+    fn pec_includes_address() {
+        // Write byte 0x5A to command 0x01 at address 0x40.
+        let pec = Pec::new().addr_write(0x40).bytes(&[0x01, 0x5A]).finish();
+        assert_eq!(pec, crc8(&[0x80, 0x01, 0x5A]));
+        let read = Pec::new().addr_write(0x40).update(0x79).addr_read(0x40);
+        assert_eq!(read.finish(), crc8(&[0x80, 0x79, 0x81]));
+    }
+
+    #[test]
+    fn default_hook_skips_telemetry() {
+        assert!(default_pec_required(CommandCode::VoutCommand));
+        assert!(default_pec_required(CommandCode::StoreUserAll));
+        assert!(!default_pec_required(CommandCode::ReadVout));
+        assert!(!default_pec_required(CommandCode::ReadEin));
+        assert!(default_pec_required(CommandCode::PmbusRevision));
+    }
+}
@@ -0,0 +1,127 @@
+/// What a device does when a fault is detected, from bits \[7:6\] of a
+/// `*_FAULT_RESPONSE` byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaultAction {
+    /// Keep operating without interruption.
+    Continue = 0b00,
+    /// Keep operating for the delay time, then shut down if the fault is
+    /// still present and apply the retry setting.
+    DelayedShutdown = 0b01,
+    /// Shut down immediately and apply the retry setting.
+    Shutdown = 0b10,
+    /// Disable the output while the fault is present, and re-enable it
+    /// once the fault clears.
+    DisableWhilePresent = 0b11,
+}
+
+/// A decoded `*_FAULT_RESPONSE` byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FaultResponse {
+    /// Bits \[7:6\]: what the device does on the fault.
+    ///
+    /// IOUT_OC_FAULT_RESPONSE gives these codes its own meaning, since the
+    /// device limits the current rather than ignoring it: `Continue`
+    /// (`00`) runs in constant-current mode indefinitely; `DelayedShutdown`
+    /// (`01`) runs in constant-current mode until the output falls below
+    /// IOUT_OC_LV_FAULT_LIMIT, then shuts down; `Shutdown` (`10`) runs in
+    /// constant-current mode for the delay time, then shuts down; and
+    /// `DisableWhilePresent` (`11`) shuts down immediately. The retry
+    /// setting applies after each shutdown.
+    pub action: FaultAction,
+    /// Bits \[5:3\]: 0 latches off, 1–6 retry that many times, 7 retries
+    /// indefinitely.
+    pub retries: u8,
+    /// Bits \[2:0\]: delay time, in device-specific units.
+    pub delay: u8,
+}
+
+impl FaultResponse {
+    /// Retry setting that keeps retrying until the fault clears.
+    pub const RETRY_FOREVER: u8 = 7;
+
+    /// Parse a raw response byte.
+    pub fn from_raw(raw: u8) -> Self {
+        let action = match raw >> 6 {
+            0b00 => FaultAction::Continue,
+            0b01 => FaultAction::DelayedShutdown,
+            0b10 => FaultAction::Shutdown,
+            _ => FaultAction::DisableWhilePresent,
+        };
+        Self {
+            action,
+            retries: (raw >> 3) & 0x07,
+            delay: raw & 0x07,
+        }
+    }
+
+    /// Encode to a raw response byte, or `None` if `retries` or `delay`
+    /// doesn't fit in 3 bits.
+    pub fn to_raw(self) -> Option<u8> {
+        if self.retries > 7 || self.delay > 7 {
+            return None;
+        }
+        Some(((self.action as u8) << 6) | (self.retries << 3) | self.delay)
+    }
+}
+
+/// A fault limit together with the response to apply when it is crossed.
+///
+/// The limit is in engineering units and is encoded like
+/// [`set_limit`](crate::PmbusAdaptor::set_limit) would.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Protection {
+    /// The `*_FAULT_LIMIT` value: volts, amps, °C or watts depending on
+    /// the domain.
+    pub limit: f32,
+    /// The matching `*_FAULT_RESPONSE` setting.
+    pub response: FaultResponse,
+}
+
+/// Every `*_FAULT_RESPONSE` setting of one page, as returned by
+/// [`read_all_fault_responses`](crate::PmbusAdaptor::read_all_fault_responses).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FaultResponses {
+    /// VOUT_OV_FAULT_RESPONSE (0x41).
+    pub vout_ov: FaultResponse,
+    /// VOUT_UV_FAULT_RESPONSE (0x45).
+    pub vout_uv: FaultResponse,
+    /// IOUT_OC_FAULT_RESPONSE (0x47).
+    pub iout_oc: FaultResponse,
+    /// IOUT_OC_LV_FAULT_RESPONSE (0x49).
+    pub iout_oc_lv: FaultResponse,
+    /// IOUT_UC_FAULT_RESPONSE (0x4C).
+    pub iout_uc: FaultResponse,
+    /// OT_FAULT_RESPONSE (0x50).
+    pub ot: FaultResponse,
+    /// UT_FAULT_RESPONSE (0x54).
+    pub ut: FaultResponse,
+    /// VIN_OV_FAULT_RESPONSE (0x56).
+    pub vin_ov: FaultResponse,
+    /// VIN_UV_FAULT_RESPONSE (0x5A).
+    pub vin_uv: FaultResponse,
+    /// IIN_OC_FAULT_RESPONSE (0x5C).
+    pub iin_oc: FaultResponse,
+    /// TON_MAX_FAULT_RESPONSE (0x63).
+    pub ton_max: FaultResponse,
+    /// POUT_OP_FAULT_RESPONSE (0x69).
+    pub pout_op: FaultResponse,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fault_response_roundtrip() {
+        // Shut down, retry forever, delay 0: a common default.
+        let r = FaultResponse::from_raw(0xB8);
+        assert_eq!(r.action, FaultAction::Shutdown);
+        assert_eq!(r.retries, FaultResponse::RETRY_FOREVER);
+        assert_eq!(r.delay, 0);
+        for raw in 0u8..=255 {
+            assert_eq!(FaultResponse::from_raw(raw).to_raw(), Some(raw));
+        }
+        let bad = FaultResponse { retries: 8, ..r };
+        assert_eq!(bad.to_raw(), None);
+    }
+}
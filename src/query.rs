@@ -0,0 +1,94 @@
+/// Numeric format reported by QUERY (0x1A) bits \[4:2\].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFormat {
+    /// LINEAR11 (`000`).
+    Linear11,
+    /// 16-bit signed (`001`).
+    ///
+    /// PMBus Part II calls this encoding "16 bit signed", but devices
+    /// report it for the commands that follow VOUT_MODE, so the decoders in
+    /// this crate treat it as ULINEAR16 with the VOUT_MODE exponent.
+    ULinear16,
+    /// DIRECT, with coefficients from COEFFICIENTS (`011`).
+    Direct,
+    /// 8-bit unsigned (`100`).
+    U8,
+    /// VID code (`101`).
+    Vid,
+    /// Manufacturer specific (`110`).
+    Manufacturer,
+    /// Not a numeric value, e.g. a bit field (`111`).
+    NotNumeric,
+    /// The reserved encoding `010`.
+    Reserved,
+}
+
+impl DataFormat {
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0x07 {
+            0b000 => Self::Linear11,
+            0b001 => Self::ULinear16,
+            0b011 => Self::Direct,
+            0b100 => Self::U8,
+            0b101 => Self::Vid,
+            0b110 => Self::Manufacturer,
+            0b111 => Self::NotNumeric,
+            _ => Self::Reserved,
+        }
+    }
+
+    /// Whether values in this format take two bytes on the wire, when that
+    /// follows from the format alone.
+    pub fn is_word(self) -> Option<bool> {
+        match self {
+            Self::Linear11 | Self::ULinear16 | Self::Direct => Some(true),
+            Self::U8 => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// Decoded QUERY (0x1A) response byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryResponse {
+    /// Bit 7: the command is supported.
+    pub supported: bool,
+    /// Bit 6: the command can be written.
+    pub writable: bool,
+    /// Bit 5: the command can be read.
+    pub readable: bool,
+    /// Bits \[4:2\]: the value's numeric format.
+    pub format: DataFormat,
+}
+
+impl QueryResponse {
+    /// Parse a raw QUERY response byte. Bits \[1:0\] are reserved.
+    pub fn from_raw(raw: u8) -> Self {
+        Self {
+            supported: raw & 0x80 != 0,
+            writable: raw & 0x40 != 0,
+            readable: raw & 0x20 != 0,
+            format: DataFormat::from_bits(raw >> 2),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        // Supported, read/write, LINEAR11.
+        let q = QueryResponse::from_raw(0xE0);
+        assert!(q.supported && q.writable && q.readable);
+        assert_eq!(q.format, DataFormat::Linear11);
+        // Supported, read-only, 8-bit unsigned.
+        let q = QueryResponse::from_raw(0xB0);
+        assert!(!q.writable);
+        assert_eq!(q.format, DataFormat::U8);
+        assert_eq!(q.format.is_word(), Some(false));
+        assert!(!QueryResponse::from_raw(0x00).supported);
+        assert_eq!(QueryResponse::from_raw(0xFC).format, DataFormat::NotNumeric);
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(feature = "limits")]
+use crate::commands::CommandCode;
+#[cfg(feature = "limits")]
+use crate::timing::TimingLimits;
+
+/// Commanded and measured output voltage of one rail, in volts.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct VoutPair {
+    /// VOUT_COMMAND (0x21).
+    pub commanded: f32,
+    /// READ_VOUT (0x8B).
+    pub measured: f32,
+}
+
+/// Telemetry of one rail, in volts, amps, °C and watts.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RailTelemetry {
+    /// READ_VIN (0x88).
+    pub vin: f32,
+    /// READ_VOUT (0x8B), decoded with the page's VOUT_MODE.
+    pub vout: f32,
+    /// READ_IOUT (0x8C), as returned by
+    /// [`read_iout_amps`](crate::PmbusAdaptor::read_iout_amps).
+    pub iout: f32,
+    /// READ_TEMPERATURE_1 (0x8D).
+    pub temperature: f32,
+    /// READ_POUT (0x96).
+    pub pout: f32,
+}
+
+/// Which way output current flows on a bidirectional rail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurrentDirection {
+    /// READ_IOUT is positive: the rail delivers current to the load.
+    Sourcing,
+    /// READ_IOUT is negative: current flows back into the rail.
+    Sinking,
+    /// READ_IOUT is within the deadband around zero.
+    Idle,
+}
+
+impl CurrentDirection {
+    /// Deadband used by
+    /// [`read_iout_direction`](crate::PmbusAdaptor::read_iout_direction),
+    /// in amps. Keeps sense-amplifier offset near zero load from flapping
+    /// between directions.
+    pub const DEFAULT_DEADBAND_AMPS: f32 = 0.05;
+
+    /// Classify `amps`, treating `-deadband..=deadband` as idle.
+    pub fn classify(amps: f32, deadband: f32) -> Self {
+        if amps > deadband {
+            Self::Sourcing
+        } else if amps < -deadband {
+            Self::Sinking
+        } else {
+            Self::Idle
+        }
+    }
+}
+
+/// Everything [`configure_rail`] writes to provision one rail.
+///
+/// `None` fields and empty slices are left as they are on the device.
+///
+/// [`configure_rail`]: crate::PmbusAdaptor::configure_rail
+#[cfg(feature = "limits")]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RailConfig<'a> {
+    /// VOUT_COMMAND (0x21), in volts.
+    pub vout_command: f32,
+    /// VOUT_MAX (0x24), in volts.
+    pub vout_max: Option<f32>,
+    /// VOUT_MARGIN_HIGH (0x25), in volts.
+    pub vout_margin_high: Option<f32>,
+    /// VOUT_MARGIN_LOW (0x26), in volts.
+    pub vout_margin_low: Option<f32>,
+    /// Fault/warning limits in engineering units, encoded as by
+    /// [`set_limit`](crate::PmbusAdaptor::set_limit).
+    pub limits: &'a [(CommandCode, f32)],
+    /// Raw fault response bytes (VOUT_OV_FAULT_RESPONSE and friends).
+    pub fault_responses: &'a [(CommandCode, u8)],
+    /// TON_*/TOFF_* sequencing.
+    pub timing: Option<TimingLimits>,
+    /// ON_OFF_CONFIG (0x02), written last.
+    pub on_off_config: Option<u8>,
+    /// Issue STORE_USER_ALL once everything is written.
+    pub store: bool,
+    /// Re-read WRITE_PROTECT after each group of writes and abort with
+    /// [`PmbusError::WriteProtected`](crate::PmbusError::WriteProtected) if
+    /// another master or a watchdog set it again.
+    pub recheck_write_protect: bool,
+}
@@ -0,0 +1,51 @@
+use heapless::Vec;
+
+/// Maximum number of writes held in the dry-run log.
+pub const LOG_CAPACITY: usize = 32;
+
+/// A write captured in dry-run mode instead of being sent on the bus.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedWrite {
+    /// 7-bit device address.
+    pub addr: u8,
+    /// First byte after the address (the command code, or the extended prefix).
+    pub command: u8,
+    /// Remaining bytes as they would appear on the wire, excluding PEC.
+    /// Block writes include the count byte.
+    pub data: Vec<u8, 33>,
+}
+
+impl RecordedWrite {
+    /// Build a record from `command` followed by the given payload parts.
+    ///
+    /// Returns `None` if the payload doesn't fit.
+    pub fn new(addr: u8, command: u8, parts: &[&[u8]]) -> Option<Self> {
+        let mut data = Vec::new();
+        for part in parts {
+            data.extend_from_slice(part).ok()?;
+        }
+        Some(Self {
+            addr,
+            command,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_write_concatenates_parts() {
+        let tx = RecordedWrite::new(0x40, 0x99, &[&[3], b"ABC"]).unwrap();
+        assert_eq!(tx.command, 0x99);
+        assert_eq!(tx.data.as_slice(), &[3, b'A', b'B', b'C']);
+    }
+
+    #[test]
+    fn recorded_write_overflow_returns_none() {
+        assert!(RecordedWrite::new(0x40, 0x99, &[&[32], &[0u8; 32]]).is_some());
+        assert!(RecordedWrite::new(0x40, 0x99, &[&[33], &[0u8; 33]]).is_none());
+    }
+}
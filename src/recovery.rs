@@ -0,0 +1,24 @@
+/// Manual control of the SCL and SDA lines, for clocking a stuck device
+/// free with [`bus_recovery`](crate::PmbusAdaptor::bus_recovery).
+///
+/// Typically implemented by temporarily switching the I2C pins to
+/// open-drain GPIOs. "High" means released, so the pull-up takes the line
+/// high unless a device holds it low.
+pub trait BusRecoveryPins {
+    type Error;
+
+    /// Release SCL (`true`) or drive it low (`false`).
+    fn set_scl(&mut self, high: bool) -> Result<(), Self::Error>;
+
+    /// Release SDA (`true`) or drive it low (`false`).
+    fn set_sda(&mut self, high: bool) -> Result<(), Self::Error>;
+
+    /// Sample SDA.
+    fn sda_is_high(&mut self) -> Result<bool, Self::Error>;
+}
+
+/// Clock pulses sent before giving up: enough to finish any byte plus ACK.
+pub(crate) const MAX_CLOCKS: u8 = 9;
+
+/// Half of a 100 kHz SCL period, in microseconds.
+pub(crate) const HALF_PERIOD_US: u32 = 5;
@@ -1,5 +1,9 @@
+use core::fmt;
+
 use bitflags::bitflags;
 
+use crate::commands::CommandCode;
+
 bitflags! {
     /// STATUS_BYTE register (0x78) — 8-bit summary status.
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -154,70 +158,515 @@ bitflags! {
 }
 
 // Convenience constructors for building from raw bus values.
+//
+// `from_raw` drops bits that have no named flag. `from_raw_retain` keeps
+// them, so a read-modify-write of a status register (e.g. to clear one
+// flag) doesn't discard vendor-extended bits the device set.
 impl StatusByte {
     pub fn from_raw(raw: u8) -> Self {
         Self::from_bits_truncate(raw)
     }
+
+    pub fn from_raw_retain(raw: u8) -> Self {
+        Self::from_bits_retain(raw)
+    }
 }
 
 impl StatusWord {
     pub fn from_raw(raw: u16) -> Self {
         Self::from_bits_truncate(raw)
     }
+
+    pub fn from_raw_retain(raw: u16) -> Self {
+        Self::from_bits_retain(raw)
+    }
+
+    /// Return `true` if every bit is set, the pattern a floating bus reads
+    /// as.
+    pub fn is_all_ones(&self) -> bool {
+        self.bits() == 0xFFFF
+    }
+
+    /// Version of the [`to_transport`](Self::to_transport) encoding.
+    ///
+    /// Bumped only if the layout ever changes; adding flag names never
+    /// changes it.
+    pub const TRANSPORT_VERSION: u8 = 1;
+
+    /// Serialize for sending over a link to a peer that may run a
+    /// different crate version.
+    ///
+    /// Version 1 is the STATUS_WORD register layout exactly as PMBus 1.4
+    /// defines it, bit for bit, including any bits without a flag name.
+    pub fn to_transport(&self) -> u16 {
+        self.bits()
+    }
+
+    /// Inverse of [`to_transport`](Self::to_transport). Unknown bits are
+    /// retained, so forwarding a status through an older decoder is
+    /// lossless.
+    pub fn from_transport(raw: u16) -> Self {
+        Self::from_bits_retain(raw)
+    }
 }
 
 impl StatusVout {
     pub fn from_raw(raw: u8) -> Self {
         Self::from_bits_truncate(raw)
     }
+
+    pub fn from_raw_retain(raw: u8) -> Self {
+        Self::from_bits_retain(raw)
+    }
 }
 
 impl StatusIout {
     pub fn from_raw(raw: u8) -> Self {
         Self::from_bits_truncate(raw)
     }
+
+    pub fn from_raw_retain(raw: u8) -> Self {
+        Self::from_bits_retain(raw)
+    }
 }
 
 impl StatusInput {
     pub fn from_raw(raw: u8) -> Self {
         Self::from_bits_truncate(raw)
     }
+
+    pub fn from_raw_retain(raw: u8) -> Self {
+        Self::from_bits_retain(raw)
+    }
 }
 
 impl StatusTemperature {
     pub fn from_raw(raw: u8) -> Self {
         Self::from_bits_truncate(raw)
     }
+
+    pub fn from_raw_retain(raw: u8) -> Self {
+        Self::from_bits_retain(raw)
+    }
 }
 
 impl StatusCml {
     pub fn from_raw(raw: u8) -> Self {
         Self::from_bits_truncate(raw)
     }
+
+    pub fn from_raw_retain(raw: u8) -> Self {
+        Self::from_bits_retain(raw)
+    }
 }
 
 impl StatusOther {
     pub fn from_raw(raw: u8) -> Self {
         Self::from_bits_truncate(raw)
     }
+
+    pub fn from_raw_retain(raw: u8) -> Self {
+        Self::from_bits_retain(raw)
+    }
 }
 
 impl StatusFans12 {
     pub fn from_raw(raw: u8) -> Self {
         Self::from_bits_truncate(raw)
     }
+
+    pub fn from_raw_retain(raw: u8) -> Self {
+        Self::from_bits_retain(raw)
+    }
 }
 
 impl StatusFans34 {
     pub fn from_raw(raw: u8) -> Self {
         Self::from_bits_truncate(raw)
     }
+
+    pub fn from_raw_retain(raw: u8) -> Self {
+        Self::from_bits_retain(raw)
+    }
+}
+
+// Flag names for logging, e.g. `for name in status.set_flag_names()`.
+macro_rules! impl_set_flag_names {
+    ($($ty:ty),* $(,)?) => {$(
+        impl $ty {
+            /// Names of the set flags, in the order they are declared.
+            /// Bits without a named flag are skipped.
+            pub fn set_flag_names(self) -> impl Iterator<Item = &'static str> {
+                self.iter_names().map(|(name, _)| name)
+            }
+        }
+    )*};
+}
+
+impl_set_flag_names!(
+    StatusByte,
+    StatusWord,
+    StatusVout,
+    StatusIout,
+    StatusInput,
+    StatusTemperature,
+    StatusCml,
+    StatusOther,
+    StatusFans12,
+    StatusFans34,
+);
+
+/// Policy for clearing latched faults after `read_all_status` reports them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AutoClear {
+    /// Leave all status bits latched (default).
+    #[default]
+    Off,
+    /// Write the bits that were read back to each status register (write-1-to-clear).
+    WriteBack,
+    /// Issue CLEAR_FAULTS (0x03) once all registers have been read.
+    ClearFaults,
+}
+
+/// A snapshot of every standard status register.
+///
+/// Registers whose summary bit in STATUS_WORD is clear are not read and
+/// are reported as empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusSnapshot {
+    pub word: StatusWord,
+    pub vout: StatusVout,
+    pub iout: StatusIout,
+    pub input: StatusInput,
+    pub temperature: StatusTemperature,
+    pub cml: StatusCml,
+    pub other: StatusOther,
+    pub mfr_specific: u8,
+    pub fans_12: StatusFans12,
+    pub fans_34: StatusFans34,
+}
+
+impl StatusSnapshot {
+    /// Create a snapshot with no flags set.
+    pub fn empty() -> Self {
+        Self {
+            word: StatusWord::empty(),
+            vout: StatusVout::empty(),
+            iout: StatusIout::empty(),
+            input: StatusInput::empty(),
+            temperature: StatusTemperature::empty(),
+            cml: StatusCml::empty(),
+            other: StatusOther::empty(),
+            mfr_specific: 0,
+            fans_12: StatusFans12::empty(),
+            fans_34: StatusFans34::empty(),
+        }
+    }
+
+    /// STATUS_BYTE as carried in the low byte of STATUS_WORD.
+    pub fn byte(&self) -> StatusByte {
+        StatusByte::from_raw(self.word.bits() as u8)
+    }
+
+    /// Return `true` if no flag is set in any register.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::empty()
+    }
+}
+
+impl Default for StatusSnapshot {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// Length of a full status blob for [`decode_status_blob`].
+pub const STATUS_BLOB_LEN: usize = 12;
+
+/// Decode a captured dump of the status registers 0x78..=0x82.
+///
+/// The blob holds each register in command-code order, STATUS_WORD as two
+/// bytes low byte first: `[BYTE, WORD_LO, WORD_HI, VOUT, IOUT, INPUT,
+/// TEMPERATURE, CML, OTHER, MFR_SPECIFIC, FANS_1_2, FANS_3_4]`. STATUS_BYTE
+/// duplicates the low byte of STATUS_WORD and is ignored. Undefined bits
+/// are kept, and registers past the end of a short blob are reported as
+/// empty.
+pub fn decode_status_blob(blob: &[u8]) -> StatusSnapshot {
+    let at = |i: usize| blob.get(i).copied().unwrap_or(0);
+    StatusSnapshot {
+        word: StatusWord::from_raw_retain(u16::from_le_bytes([at(1), at(2)])),
+        vout: StatusVout::from_raw_retain(at(3)),
+        iout: StatusIout::from_raw_retain(at(4)),
+        input: StatusInput::from_raw_retain(at(5)),
+        temperature: StatusTemperature::from_raw_retain(at(6)),
+        cml: StatusCml::from_raw_retain(at(7)),
+        other: StatusOther::from_raw_retain(at(8)),
+        mfr_specific: at(9),
+        fans_12: StatusFans12::from_raw_retain(at(10)),
+        fans_34: StatusFans34::from_raw_retain(at(11)),
+    }
+}
+
+/// Every standard status register, in command-code order.
+pub const STATUS_COMMANDS: [CommandCode; 11] = [
+    CommandCode::StatusByte,
+    CommandCode::StatusWord,
+    CommandCode::StatusVout,
+    CommandCode::StatusIout,
+    CommandCode::StatusInput,
+    CommandCode::StatusTemperature,
+    CommandCode::StatusCml,
+    CommandCode::StatusOther,
+    CommandCode::StatusMfrSpecific,
+    CommandCode::StatusFans12,
+    CommandCode::StatusFans34,
+];
+
+/// A status register that SMBALERT_MASK (0x1B) can mask.
+///
+/// STATUS_BYTE and STATUS_WORD only summarize the others and have no mask
+/// of their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusRegister {
+    Vout,
+    Iout,
+    Input,
+    Temperature,
+    Cml,
+    Other,
+    MfrSpecific,
+    Fans12,
+    Fans34,
+}
+
+impl StatusRegister {
+    /// The status command this register is read with.
+    pub fn command(self) -> CommandCode {
+        match self {
+            Self::Vout => CommandCode::StatusVout,
+            Self::Iout => CommandCode::StatusIout,
+            Self::Input => CommandCode::StatusInput,
+            Self::Temperature => CommandCode::StatusTemperature,
+            Self::Cml => CommandCode::StatusCml,
+            Self::Other => CommandCode::StatusOther,
+            Self::MfrSpecific => CommandCode::StatusMfrSpecific,
+            Self::Fans12 => CommandCode::StatusFans12,
+            Self::Fans34 => CommandCode::StatusFans34,
+        }
+    }
+
+    /// The maskable register read with `cmd`, if any.
+    pub fn from_command(cmd: CommandCode) -> Option<Self> {
+        Some(match cmd {
+            CommandCode::StatusVout => Self::Vout,
+            CommandCode::StatusIout => Self::Iout,
+            CommandCode::StatusInput => Self::Input,
+            CommandCode::StatusTemperature => Self::Temperature,
+            CommandCode::StatusCml => Self::Cml,
+            CommandCode::StatusOther => Self::Other,
+            CommandCode::StatusMfrSpecific => Self::MfrSpecific,
+            CommandCode::StatusFans12 => Self::Fans12,
+            CommandCode::StatusFans34 => Self::Fans34,
+            _ => return None,
+        })
+    }
+}
+
+/// Input supply state, as classified by `PmbusAdaptor::input_state`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputState {
+    /// READ_VIN is below a tenth of VIN_OFF: nothing is connected.
+    Absent,
+    /// Input is present but below VIN_OFF, or the unit reports it is off for
+    /// low input and VIN hasn't reached VIN_ON yet.
+    BelowTurnOn,
+    /// Above the turn-off threshold with no input voltage fault or warning.
+    Normal,
+    /// VIN_UV warning or fault is flagged.
+    UnderVoltage,
+    /// VIN_OV warning or fault is flagged.
+    OverVoltage,
+}
+
+impl InputState {
+    /// Classify from READ_VIN, the VIN_ON/VIN_OFF thresholds and
+    /// STATUS_INPUT, all in volts.
+    ///
+    /// Latched faults take priority over the measured voltage, so an OV
+    /// fault that shut the unit down is still reported as such. Between
+    /// VIN_OFF and VIN_ON the hysteresis band is resolved by UNIT_OFF_LOW_VIN.
+    pub fn classify(vin: f32, vin_on: f32, vin_off: f32, status: StatusInput) -> Self {
+        if status.intersects(StatusInput::VIN_OV_FAULT | StatusInput::VIN_OV_WARNING) {
+            Self::OverVoltage
+        } else if status.intersects(StatusInput::VIN_UV_FAULT | StatusInput::VIN_UV_WARNING) {
+            Self::UnderVoltage
+        } else if vin < vin_off / 10.0 {
+            Self::Absent
+        } else if vin < vin_off || (vin < vin_on && status.contains(StatusInput::UNIT_OFF_LOW_VIN))
+        {
+            Self::BelowTurnOn
+        } else {
+            Self::Normal
+        }
+    }
+}
+
+/// A status register value, typed by the command it was read from.
+///
+/// Bits without a named flag are kept, and are shown in hex by `Display`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusValue {
+    Byte(StatusByte),
+    Word(StatusWord),
+    Vout(StatusVout),
+    Iout(StatusIout),
+    Input(StatusInput),
+    Temperature(StatusTemperature),
+    Cml(StatusCml),
+    Other(StatusOther),
+    MfrSpecific(u8),
+    Fans12(StatusFans12),
+    Fans34(StatusFans34),
+}
+
+impl StatusValue {
+    /// Wrap a raw register value read from `cmd`.
+    ///
+    /// Returns `None` if `cmd` isn't one of [`STATUS_COMMANDS`]. Only
+    /// STATUS_WORD uses the high byte of `raw`.
+    pub fn from_raw(cmd: CommandCode, raw: u16) -> Option<Self> {
+        let byte = raw as u8;
+        Some(match cmd {
+            CommandCode::StatusByte => Self::Byte(StatusByte::from_raw_retain(byte)),
+            CommandCode::StatusWord => Self::Word(StatusWord::from_raw_retain(raw)),
+            CommandCode::StatusVout => Self::Vout(StatusVout::from_raw_retain(byte)),
+            CommandCode::StatusIout => Self::Iout(StatusIout::from_raw_retain(byte)),
+            CommandCode::StatusInput => Self::Input(StatusInput::from_raw_retain(byte)),
+            CommandCode::StatusTemperature => {
+                Self::Temperature(StatusTemperature::from_raw_retain(byte))
+            }
+            CommandCode::StatusCml => Self::Cml(StatusCml::from_raw_retain(byte)),
+            CommandCode::StatusOther => Self::Other(StatusOther::from_raw_retain(byte)),
+            CommandCode::StatusMfrSpecific => Self::MfrSpecific(byte),
+            CommandCode::StatusFans12 => Self::Fans12(StatusFans12::from_raw_retain(byte)),
+            CommandCode::StatusFans34 => Self::Fans34(StatusFans34::from_raw_retain(byte)),
+            _ => return None,
+        })
+    }
+
+    /// The command this value belongs to.
+    pub fn command(&self) -> CommandCode {
+        match self {
+            Self::Byte(_) => CommandCode::StatusByte,
+            Self::Word(_) => CommandCode::StatusWord,
+            Self::Vout(_) => CommandCode::StatusVout,
+            Self::Iout(_) => CommandCode::StatusIout,
+            Self::Input(_) => CommandCode::StatusInput,
+            Self::Temperature(_) => CommandCode::StatusTemperature,
+            Self::Cml(_) => CommandCode::StatusCml,
+            Self::Other(_) => CommandCode::StatusOther,
+            Self::MfrSpecific(_) => CommandCode::StatusMfrSpecific,
+            Self::Fans12(_) => CommandCode::StatusFans12,
+            Self::Fans34(_) => CommandCode::StatusFans34,
+        }
+    }
+
+    /// The raw register value.
+    pub fn bits(&self) -> u16 {
+        match *self {
+            Self::Byte(f) => f.bits() as u16,
+            Self::Word(f) => f.bits(),
+            Self::Vout(f) => f.bits() as u16,
+            Self::Iout(f) => f.bits() as u16,
+            Self::Input(f) => f.bits() as u16,
+            Self::Temperature(f) => f.bits() as u16,
+            Self::Cml(f) => f.bits() as u16,
+            Self::Other(f) => f.bits() as u16,
+            Self::MfrSpecific(b) => b as u16,
+            Self::Fans12(f) => f.bits() as u16,
+            Self::Fans34(f) => f.bits() as u16,
+        }
+    }
+}
+
+/// Formats as the set flag names joined by `" | "`, or `"(none)"`.
+impl fmt::Display for StatusValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.bits() == 0 {
+            return f.write_str("(none)");
+        }
+        match self {
+            Self::Byte(v) => bitflags::parser::to_writer(v, &mut *f),
+            Self::Word(v) => bitflags::parser::to_writer(v, &mut *f),
+            Self::Vout(v) => bitflags::parser::to_writer(v, &mut *f),
+            Self::Iout(v) => bitflags::parser::to_writer(v, &mut *f),
+            Self::Input(v) => bitflags::parser::to_writer(v, &mut *f),
+            Self::Temperature(v) => bitflags::parser::to_writer(v, &mut *f),
+            Self::Cml(v) => bitflags::parser::to_writer(v, &mut *f),
+            Self::Other(v) => bitflags::parser::to_writer(v, &mut *f),
+            Self::MfrSpecific(b) => write!(f, "{b:#04x}"),
+            Self::Fans12(v) => bitflags::parser::to_writer(v, &mut *f),
+            Self::Fans34(v) => bitflags::parser::to_writer(v, &mut *f),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn status_word_transport_is_lossless() {
+        // VOUT | POWER_GOOD_NEG | OFF, the register layout itself.
+        let word = StatusWord::VOUT | StatusWord::POWER_GOOD_NEG | StatusWord::OFF;
+        assert_eq!(word.to_transport(), 0x8840);
+        for raw in [0x0000, 0x8840, 0xFFFF] {
+            assert_eq!(StatusWord::from_transport(raw).to_transport(), raw);
+        }
+    }
+
+    #[test]
+    fn set_flag_names_skips_unnamed_bits() {
+        let word = StatusWord::from_raw_retain(0x8841);
+        let names: heapless::Vec<&str, 16> = word.set_flag_names().collect();
+        assert_eq!(
+            names,
+            ["OFF", "NONE_OF_THE_ABOVE", "VOUT", "POWER_GOOD_NEG"]
+        );
+        assert_eq!(StatusCml::empty().set_flag_names().count(), 0);
+        let fans = StatusFans34::from_raw_retain(0x83);
+        assert!(fans.set_flag_names().eq(["FAN3_FAULT"]));
+    }
+
+    #[test]
+    fn input_state_classification() {
+        let ok = StatusInput::empty();
+        assert_eq!(InputState::classify(0.2, 9.0, 8.0, ok), InputState::Absent);
+        assert_eq!(
+            InputState::classify(7.5, 9.0, 8.0, ok),
+            InputState::BelowTurnOn
+        );
+        assert_eq!(InputState::classify(12.0, 9.0, 8.0, ok), InputState::Normal);
+        // Inside the hysteresis band the unit's own off bit decides.
+        assert_eq!(InputState::classify(8.5, 9.0, 8.0, ok), InputState::Normal);
+        let off = StatusInput::UNIT_OFF_LOW_VIN;
+        assert_eq!(
+            InputState::classify(8.5, 9.0, 8.0, off),
+            InputState::BelowTurnOn
+        );
+        assert_eq!(InputState::classify(9.5, 9.0, 8.0, off), InputState::Normal);
+        let uv = StatusInput::VIN_UV_WARNING;
+        assert_eq!(
+            InputState::classify(9.5, 9.0, 8.0, uv),
+            InputState::UnderVoltage
+        );
+        let ov = StatusInput::VIN_OV_FAULT | StatusInput::UNIT_OFF_LOW_VIN;
+        assert_eq!(
+            InputState::classify(0.0, 9.0, 8.0, ov),
+            InputState::OverVoltage
+        );
+    }
+
     #[test]
     fn status_byte_flags() {
         let s = StatusByte::from_raw(0x44);
@@ -280,9 +729,83 @@ mod tests {
         assert!(s.contains(StatusFans34::FAN4_FAULT));
     }
 
+    #[test]
+    fn status_retain_keeps_undefined_bits() {
+        // Bits 0x08..0x01 are undefined in STATUS_TEMPERATURE
+        assert_eq!(StatusTemperature::from_raw(0x85).bits(), 0x80);
+        let s = StatusTemperature::from_raw_retain(0x85);
+        assert_eq!(s.bits(), 0x85);
+        assert!(s.contains(StatusTemperature::OT_FAULT));
+        assert_eq!(StatusCml::from_raw_retain(0x04).bits(), 0x04);
+    }
+
     #[test]
     fn status_empty() {
         assert!(StatusByte::from_raw(0).is_empty());
         assert!(StatusWord::from_raw(0).is_empty());
     }
+
+    #[test]
+    fn snapshot_byte_from_word() {
+        let mut snap = StatusSnapshot::empty();
+        assert!(snap.is_empty());
+        snap.word = StatusWord::from_raw(0x8044);
+        assert_eq!(snap.byte(), StatusByte::OFF | StatusByte::TEMPERATURE);
+        assert!(!snap.is_empty());
+    }
+
+    #[test]
+    fn status_blob_by_position() {
+        let blob = [0x44, 0x44, 0x80, 0x80, 0, 0, 0x40, 0, 0, 0x01, 0, 0];
+        let snap = decode_status_blob(&blob);
+        assert_eq!(snap.word.bits(), 0x8044);
+        assert_eq!(snap.vout, StatusVout::OV_FAULT);
+        assert_eq!(snap.temperature, StatusTemperature::OT_WARNING);
+        assert_eq!(snap.mfr_specific, 0x01);
+        assert!(decode_status_blob(&[]).is_empty());
+        assert_eq!(decode_status_blob(&blob[..4]).vout, StatusVout::OV_FAULT);
+    }
+
+    #[test]
+    fn auto_clear_defaults_off() {
+        assert_eq!(AutoClear::default(), AutoClear::Off);
+    }
+
+    #[test]
+    fn status_value_dispatch() {
+        for cmd in STATUS_COMMANDS {
+            assert_eq!(StatusValue::from_raw(cmd, 0).unwrap().command(), cmd);
+        }
+        assert!(StatusValue::from_raw(CommandCode::ReadVout, 0).is_none());
+
+        let v = StatusValue::from_raw(CommandCode::StatusWord, 0x8840).unwrap();
+        assert_eq!(v, StatusValue::Word(StatusWord::from_raw(0x8840)));
+        assert_eq!(v.bits(), 0x8840);
+    }
+
+    #[test]
+    fn status_value_display() {
+        extern crate std;
+        use std::string::ToString;
+
+        let v = StatusValue::from_raw(CommandCode::StatusByte, 0xC0).unwrap();
+        assert_eq!(v.to_string(), "BUSY | OFF");
+        let v = StatusValue::from_raw(CommandCode::StatusCml, 0x00).unwrap();
+        assert_eq!(v.to_string(), "(none)");
+        let v = StatusValue::from_raw(CommandCode::StatusMfrSpecific, 0x81).unwrap();
+        assert_eq!(v.to_string(), "0x81");
+    }
+
+    #[test]
+    fn status_register_commands() {
+        for cmd in STATUS_COMMANDS {
+            match StatusRegister::from_command(cmd) {
+                Some(reg) => assert_eq!(reg.command(), cmd),
+                None => assert!(matches!(
+                    cmd,
+                    CommandCode::StatusByte | CommandCode::StatusWord
+                )),
+            }
+        }
+    }
 }
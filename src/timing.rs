@@ -0,0 +1,22 @@
+/// Start-up and shut-down timing configuration, in milliseconds.
+///
+/// Groups the sequencing registers that are always configured together:
+/// the delay and ramp times, and the TON_MAX/TOFF_MAX protection windows
+/// that bound them. All six are LINEAR11 on the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TimingLimits {
+    /// TON_DELAY (0x60): enable to start of the output ramp.
+    pub ton_delay: f32,
+    /// TON_RISE (0x61): duration of the output ramp.
+    pub ton_rise: f32,
+    /// TON_MAX_FAULT_LIMIT (0x62): start of ramp to regulation, beyond
+    /// which a TON_MAX fault is raised. 0 disables the check.
+    pub ton_max_fault_limit: f32,
+    /// TOFF_DELAY (0x64): disable to start of the output fall.
+    pub toff_delay: f32,
+    /// TOFF_FALL (0x65): duration of the output fall.
+    pub toff_fall: f32,
+    /// TOFF_MAX_WARN_LIMIT (0x66): end of TOFF_DELAY to output off, beyond
+    /// which a TOFF_MAX warning is raised. 0 disables the check.
+    pub toff_max_warn_limit: f32,
+}
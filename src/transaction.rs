@@ -0,0 +1,154 @@
+use heapless::Vec;
+
+/// One SMBus transaction, as dispatched by `PmbusAdaptor::execute`.
+///
+/// Every command method is expressed as one of these, so PEC, dry-run
+/// recording and bus recovery apply the same way to all of them. Codes are
+/// raw bytes so manufacturer-specific commands fit as well.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Transaction<'a> {
+    /// Send byte: `[code]`.
+    SendByte { addr: u8, code: u8 },
+    /// Write byte: `[code, data]`.
+    WriteByte { addr: u8, code: u8, data: u8 },
+    /// Read byte: `[code]`, repeated start, 1 byte.
+    ReadByte { addr: u8, code: u8 },
+    /// Write word: `[code, lo, hi]`.
+    WriteWord { addr: u8, code: u8, data: u16 },
+    /// Read word: `[code]`, repeated start, 2 bytes (LE).
+    ReadWord { addr: u8, code: u8 },
+    /// Process call: `[code, lo, hi]`, repeated start, 2 bytes (LE).
+    ProcessCall { addr: u8, code: u8, data: u16 },
+    /// Block write: `[code, count, data..]`. The count byte is derived from
+    /// `data`, which must not exceed 255 bytes.
+    BlockWrite { addr: u8, code: u8, data: &'a [u8] },
+    /// Block read: `[code]`, repeated start, `[count, data..]`.
+    BlockRead { addr: u8, code: u8 },
+    /// Block write-block read process call.
+    BlockProcessCall { addr: u8, code: u8, data: &'a [u8] },
+    /// Write `write` (code first), then fill `read` after a repeated start.
+    /// An empty `read` makes this a plain write.
+    WriteRead {
+        addr: u8,
+        write: &'a [u8],
+        read: &'a mut [u8],
+    },
+}
+
+impl Transaction<'_> {
+    /// The 7-bit device address.
+    pub fn addr(&self) -> u8 {
+        match *self {
+            Self::SendByte { addr, .. }
+            | Self::WriteByte { addr, .. }
+            | Self::ReadByte { addr, .. }
+            | Self::WriteWord { addr, .. }
+            | Self::ReadWord { addr, .. }
+            | Self::ProcessCall { addr, .. }
+            | Self::BlockWrite { addr, .. }
+            | Self::BlockRead { addr, .. }
+            | Self::BlockProcessCall { addr, .. }
+            | Self::WriteRead { addr, .. } => addr,
+        }
+    }
+
+    /// The first byte after the address (the command code or extended prefix).
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::SendByte { code, .. }
+            | Self::WriteByte { code, .. }
+            | Self::ReadByte { code, .. }
+            | Self::WriteWord { code, .. }
+            | Self::ReadWord { code, .. }
+            | Self::ProcessCall { code, .. }
+            | Self::BlockWrite { code, .. }
+            | Self::BlockRead { code, .. }
+            | Self::BlockProcessCall { code, .. } => *code,
+            Self::WriteRead { write, .. } => write.first().copied().unwrap_or(0),
+        }
+    }
+
+    /// Return `true` if the transaction only writes to the device.
+    pub fn is_write(&self) -> bool {
+        match self {
+            Self::SendByte { .. }
+            | Self::WriteByte { .. }
+            | Self::WriteWord { .. }
+            | Self::BlockWrite { .. } => true,
+            Self::WriteRead { read, .. } => read.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+/// Data returned by a dispatched [`Transaction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    /// Write-only transaction, or a `WriteRead` that filled its own buffer.
+    Done,
+    Byte(u8),
+    Word(u16),
+    /// Block data, starting with the count byte.
+    Block(Vec<u8, 33>),
+}
+
+impl Response {
+    /// The byte of a `Byte` response.
+    pub fn byte(self) -> Option<u8> {
+        match self {
+            Self::Byte(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// The word of a `Word` response.
+    pub fn word(self) -> Option<u16> {
+        match self {
+            Self::Word(w) => Some(w),
+            _ => None,
+        }
+    }
+
+    /// The data of a `Block` response.
+    pub fn block(self) -> Option<Vec<u8, 33>> {
+        match self {
+            Self::Block(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_accessors() {
+        let tx = Transaction::WriteWord {
+            addr: 0x40,
+            code: 0x21,
+            data: 0x0266,
+        };
+        assert_eq!(tx.addr(), 0x40);
+        assert_eq!(tx.code(), 0x21);
+        assert!(tx.is_write());
+
+        let mut buf = [0u8; 2];
+        let tx = Transaction::WriteRead {
+            addr: 0x41,
+            write: &[0xFE, 0x10],
+            read: &mut buf,
+        };
+        assert_eq!(tx.code(), 0xFE);
+        assert!(!tx.is_write());
+        assert!(!Transaction::ReadByte { addr: 0, code: 0 }.is_write());
+    }
+
+    #[test]
+    fn response_accessors() {
+        assert_eq!(Response::Byte(7).byte(), Some(7));
+        assert_eq!(Response::Byte(7).word(), None);
+        assert_eq!(Response::Word(0x1234).word(), Some(0x1234));
+        assert!(Response::Done.block().is_none());
+    }
+}
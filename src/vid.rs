@@ -0,0 +1,118 @@
+use crate::formats::round_f32;
+
+/// VID code table used when VOUT_MODE selects VID mode.
+///
+/// Each table is linear: `V = offset + step * (vid - first)`, with the
+/// codes below `first` (and above `last`) meaning "output off".
+///
+/// PMBus leaves the numbering of VOUT_MODE's VID code field to the
+/// device, so there is no mapping from it here: pick the table the
+/// controller's datasheet names for its code. VR14 and SVI3 share the
+/// 5 mV grid from 0.245 V but differ in its first code, so the two aren't
+/// interchangeable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VidTable {
+    /// Intel VR12 / VR13 5 mV: 0.25 V at VID 0x01, 5 mV steps.
+    Vr12,
+    /// Intel VR12.5 / VR13 10 mV: 0.50 V at VID 0x01, 10 mV steps.
+    Vr125,
+    /// Intel VR14 5 mV: 0.245 V at VID 0x01, 5 mV steps.
+    Vr14,
+    /// AMD SVI2: 1.55 V at VID 0x00, -6.25 mV steps down to 0xF7.
+    Svi2,
+    /// AMD SVI3: 0.245 V at VID 0x00, 5 mV steps.
+    Svi3,
+}
+
+impl VidTable {
+    /// `(first valid code, last valid code, volts at first, volts per code)`.
+    fn params(self) -> (u8, u8, f32, f32) {
+        match self {
+            Self::Vr12 => (0x01, 0xFF, 0.25, 0.005),
+            Self::Vr125 => (0x01, 0xFF, 0.50, 0.010),
+            Self::Vr14 => (0x01, 0xFF, 0.245, 0.005),
+            Self::Svi2 => (0x00, 0xF7, 1.55, -0.00625),
+            Self::Svi3 => (0x00, 0xFF, 0.245, 0.005),
+        }
+    }
+}
+
+/// Decode a VID code to volts.
+///
+/// Returns `None` for codes the table defines as "output off".
+pub fn vid_to_volts(table: VidTable, vid: u8) -> Option<f32> {
+    let (first, last, offset, step) = table.params();
+    if !(first..=last).contains(&vid) {
+        return None;
+    }
+    Some(offset + step * (vid - first) as f32)
+}
+
+/// Encode volts as the nearest VID code.
+///
+/// Returns `None` if `volts` is outside the table's range by more than
+/// half a step.
+pub fn volts_to_vid(table: VidTable, volts: f32) -> Option<u8> {
+    let (first, last, offset, step) = table.params();
+    if !volts.is_finite() {
+        return None;
+    }
+    let steps = round_f32((volts - offset) / step);
+    if steps < 0.0 || steps > (last - first) as f32 {
+        return None;
+    }
+    Some(first + steps as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn intel_tables() {
+        assert_eq!(vid_to_volts(VidTable::Vr12, 0x00), None);
+        assert!(close(vid_to_volts(VidTable::Vr12, 0x01).unwrap(), 0.25));
+        assert!(close(vid_to_volts(VidTable::Vr12, 0xFF).unwrap(), 1.52));
+        assert!(close(vid_to_volts(VidTable::Vr125, 0x33).unwrap(), 1.0));
+        assert!(close(vid_to_volts(VidTable::Vr14, 0x01).unwrap(), 0.245));
+        assert_eq!(volts_to_vid(VidTable::Vr12, 1.0), Some(0x97));
+        assert_eq!(volts_to_vid(VidTable::Vr125, 1.0), Some(0x33));
+        assert_eq!(volts_to_vid(VidTable::Vr12, 0.1), None);
+    }
+
+    #[test]
+    fn amd_tables() {
+        assert!(close(vid_to_volts(VidTable::Svi2, 0x00).unwrap(), 1.55));
+        assert!(close(vid_to_volts(VidTable::Svi2, 0x50).unwrap(), 1.05));
+        assert_eq!(vid_to_volts(VidTable::Svi2, 0xF8), None);
+        assert_eq!(volts_to_vid(VidTable::Svi2, 1.05), Some(0x50));
+        assert!(close(vid_to_volts(VidTable::Svi3, 0x00).unwrap(), 0.245));
+        assert_eq!(vid_to_volts(VidTable::Vr14, 0x00), None);
+        assert_ne!(
+            volts_to_vid(VidTable::Svi3, 0.75),
+            volts_to_vid(VidTable::Vr14, 0.75)
+        );
+        assert_eq!(volts_to_vid(VidTable::Svi3, 0.75), Some(0x65));
+    }
+
+    #[test]
+    fn roundtrip_every_code() {
+        for table in [
+            VidTable::Vr12,
+            VidTable::Vr125,
+            VidTable::Vr14,
+            VidTable::Svi2,
+            VidTable::Svi3,
+        ] {
+            for vid in 0..=255u8 {
+                if let Some(v) = vid_to_volts(table, vid) {
+                    assert_eq!(volts_to_vid(table, v), Some(vid), "{table:?} {vid:#04x}");
+                }
+            }
+        }
+    }
+}
@@ -28,11 +28,9 @@ impl VoutMode {
         let param = raw & 0x1F;
 
         let mode = match mode_bits {
-            0b00 => {
-                // Sign-extend the 5-bit exponent
-                let exponent = ((param as i8) << 3) >> 3;
-                VoutModeType::ULinear16 { exponent }
-            }
+            0b00 => VoutModeType::ULinear16 {
+                exponent: Self::decode_exponent(param),
+            },
             0b01 => VoutModeType::Vid { code: param },
             0b10 => VoutModeType::Direct,
             _ => VoutModeType::IeeeHalf,
@@ -41,7 +39,24 @@ impl VoutMode {
         Self { relative, mode }
     }
 
+    /// Encode a ULINEAR16 exponent into the 5-bit two's-complement field
+    /// of bits \[4:0\], e.g. -13 → `0x13`.
+    ///
+    /// Returns `None` outside -16..=15.
+    pub fn encode_exponent(exp: i8) -> Option<u8> {
+        (-16..=15).contains(&exp).then_some((exp as u8) & 0x1F)
+    }
+
+    /// Sign-extend the 5-bit exponent field in bits \[4:0\] of `bits`;
+    /// the upper bits are ignored.
+    pub fn decode_exponent(bits: u8) -> i8 {
+        ((bits as i8) << 3) >> 3
+    }
+
     /// Encode back to a raw register byte.
+    ///
+    /// A ULINEAR16 exponent outside -16..=15 is truncated to 5 bits; check
+    /// it with [`encode_exponent`](Self::encode_exponent) first.
     pub fn to_raw(self) -> u8 {
         let rel_bit = if self.relative { 0x80 } else { 0x00 };
         match self.mode {
@@ -75,6 +90,20 @@ mod tests {
         assert_eq!(mode.to_raw(), raw);
     }
 
+    #[test]
+    fn exponent_field() {
+        assert_eq!(VoutMode::encode_exponent(-13), Some(0x13));
+        assert_eq!(VoutMode::encode_exponent(15), Some(0x0F));
+        assert_eq!(VoutMode::encode_exponent(-16), Some(0x10));
+        assert_eq!(VoutMode::encode_exponent(16), None);
+        assert_eq!(VoutMode::encode_exponent(-17), None);
+        for exp in -16..=15 {
+            let bits = VoutMode::encode_exponent(exp).unwrap();
+            assert_eq!(VoutMode::decode_exponent(bits), exp);
+            assert_eq!(VoutMode::decode_exponent(bits | 0xE0), exp);
+        }
+    }
+
     #[test]
     fn vid_mode() {
         let raw = 0x21; // mode=01, code=1
@@ -130,3 +159,28 @@ mod tests {
         }
     }
 }
+
+#[cfg(all(test, feature = "proptest"))]
+mod proptests {
+    extern crate std;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    fn vout_mode() -> impl Strategy<Value = VoutMode> {
+        let mode = prop_oneof![
+            (-16i8..=15).prop_map(|exponent| VoutModeType::ULinear16 { exponent }),
+            (0u8..32).prop_map(|code| VoutModeType::Vid { code }),
+            Just(VoutModeType::Direct),
+            Just(VoutModeType::IeeeHalf),
+        ];
+        (any::<bool>(), mode).prop_map(|(relative, mode)| VoutMode { relative, mode })
+    }
+
+    proptest! {
+        #[test]
+        fn parsed_mode_roundtrip(mode in vout_mode()) {
+            prop_assert_eq!(VoutMode::from_raw(mode.to_raw()), mode);
+        }
+    }
+}